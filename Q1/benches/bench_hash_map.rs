@@ -0,0 +1,97 @@
+
+
+use sample_Q1::ProbeHashMap;
+use sample_Q1::ProbeStrategy;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+// Enough headroom that a million keys stay under 50% load
+const TableSize: usize = 1 << 21;
+const KeyCount: u64 = 1_000_000;
+
+fn insert_keys_standard(keys: &[u64]) {
+    let mut hash_map = ProbeHashMap::<u64, u64, TableSize>::new();
+    for &key in keys {
+        match hash_map.insert(key, key) {
+            Ok(()) => {},
+            Err(error) => {
+                assert!(false, "Insertion with the standard hasher failed: {}", error);
+            },
+        }
+    }
+    black_box(hash_map.len());
+}
+
+fn insert_keys_multiplicative(keys: &[u64]) {
+    let mut hash_map = ProbeHashMap::<u64, u64, TableSize, _>::with_multiplicative_hasher();
+    for &key in keys {
+        match hash_map.insert(key, key) {
+            Ok(()) => {},
+            Err(error) => {
+                assert!(false, "Insertion with the multiplicative hasher failed: {}", error);
+            },
+        }
+    }
+    black_box(hash_map.len());
+}
+
+// A word-count pass over the Gutenberg text with the given map, reporting the
+// probe collisions the strategy produced
+fn count_words<'a, const Size: usize>(words: &'a [String], mut hash_map: ProbeHashMap<&'a str, u64, Size>) -> u64 {
+    for word in words {
+        match hash_map.get_mut(word.as_str()) {
+            Some(count) => *count += 1,
+            None => {
+                match hash_map.insert(word.as_str(), 1) {
+                    Ok(()) => {},
+                    Err(error) => {
+                        assert!(false, "Inserting a word failed: {}", error);
+                    },
+                }
+            },
+        }
+    }
+    return black_box(hash_map.collisions());
+}
+
+fn hash_map_benchmark(criterion: &mut Criterion) {
+    // Sequential IDs, the shape of the workloads the fast path is meant for
+    let keys: Vec<u64> = (0..KeyCount).collect();
+
+    criterion.bench_function("inserting a million u64 keys, RandomState", |bencher| {
+        bencher.iter(|| insert_keys_standard(black_box(&keys)));
+    });
+
+    criterion.bench_function("inserting a million u64 keys, multiplicative", |bencher| {
+        bencher.iter(|| insert_keys_multiplicative(black_box(&keys)));
+    });
+
+    // The probe strategies compared on real word-frequency keys
+    let file = match std::fs::read_to_string("./assets/98-0.txt") {
+        Ok(file) => file,
+        Err(error) => {
+            assert!(false, "Reading the asset file failed: {}", error);
+            return;
+        }
+    };
+    let words: Vec<String> = file.split_whitespace()
+        .map(|word| { return word.to_lowercase(); })
+        .collect();
+
+    criterion.bench_function("counting Gutenberg words, forward probe", |bencher| {
+        bencher.iter(|| count_words(black_box(&words), ProbeHashMap::<&str, u64, 65536>::new()));
+    });
+
+    criterion.bench_function("counting Gutenberg words, bidirectional probe", |bencher| {
+        bencher.iter(|| count_words(black_box(&words),
+            ProbeHashMap::<&str, u64, 65536>::with_probe_strategy(ProbeStrategy::Bidirectional)));
+    });
+}
+
+// Each sample allocates and fills a multi-megabyte table, so keep samples few
+criterion_group!{
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = hash_map_benchmark
+}
+
+criterion_main!(benches);
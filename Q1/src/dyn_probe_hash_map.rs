@@ -0,0 +1,316 @@
+
+
+// A runtime-capacity sibling of ProbeHashMap for callers that only know their
+// table size at runtime. It shares the storage building blocks and the probing
+// scheme with the fixed size variant, swapping the const parameter for a
+// capacity field, which also makes rebuilding at a different size possible.
+
+use crate::probe_hash_map::{Entry, FindResult, InsertionError, ProbeHashMapEntry, Storage};
+
+// How a full table picks its next capacity when a growth policy is installed.
+#[derive(Clone, Copy, Debug)]
+pub enum Growth {
+    Double, // Double the capacity, the classic amortized-constant choice
+    Factor(f64), // Multiply the capacity by the given factor, e.g. 1.5 for gentler growth
+    Fixed(usize), // Add a fixed number of slots each time
+}
+
+impl Growth {
+    /// Computes the capacity to rebuild at, guaranteeing progress of at
+    /// least one extra slot whatever the policy says.
+    /// @return The new capacity
+    fn next_capacity(&self, current: usize) -> usize {
+        let grown = match self {
+            &Growth::Double => current * 2,
+            &Growth::Factor(factor) => (current as f64 * factor).ceil() as usize,
+            &Growth::Fixed(additional) => current + additional,
+        };
+        return std::cmp::max(grown, current + 1);
+    }
+}
+
+pub struct DynProbeHashMap<K, V> {
+    random_state: std::hash::RandomState, // Use the standard hasher
+    first_index: Option<usize>, // Key to least recent key-value pair inserted / updated
+    last_index: Option<usize>, // Key to most recent key-value pair inserted / updated
+    occupied_count: usize, // Number of live entries, excluding deleted ones
+    growth: Option<Growth>, // When set, a full table rebuilds at the policy's capacity instead of failing
+    entry_array: Vec<ProbeHashMapEntry<K, V>>,
+}
+
+impl<K, V> DynProbeHashMap<K, V> {
+    pub fn with_capacity(capacity: usize) -> Self {
+        // A zero-slot table would make the hash's remainder undefined; one
+        // slot is the smallest table that behaves like a map
+        let capacity = std::cmp::max(capacity, 1);
+        let mut entry_array = Vec::with_capacity(capacity);
+        entry_array.resize_with(capacity, || { return ProbeHashMapEntry::new(); });
+        DynProbeHashMap {
+            random_state: std::hash::RandomState::new(),
+            first_index: None,
+            last_index: None,
+            occupied_count: 0,
+            growth: None,
+            entry_array,
+        }
+    }
+
+    /// @return The number of slots of the backing array
+    pub fn capacity(&self) -> usize {
+        return self.entry_array.len();
+    }
+
+    /// @return The number of live entries currently stored
+    pub fn len(&self) -> usize {
+        return self.occupied_count;
+    }
+
+    /// @return Whether the map stores no live entries
+    pub fn is_empty(&self) -> bool {
+        return self.occupied_count == 0;
+    }
+
+    /// Installs a growth policy. From then on an insert that finds the table
+    /// full rebuilds it at the capacity the policy dictates and retries,
+    /// instead of reporting ContainerFull.
+    pub fn set_growth(&mut self, growth: Growth) {
+        self.growth = Some(growth);
+    }
+}
+
+impl<K: std::hash::Hash + Eq, V, > DynProbeHashMap<K, V> {
+    /// Calculates the hash of the given key, cropped to our current capacity
+    /// @return the hash of the key cropped to [0, capacity - 1]
+    fn hash<Q>(&self, key: &Q) -> usize
+    where K: std::borrow::Borrow<Q>, Q: std::hash::Hash + Eq + ?Sized {
+        use std::hash::{BuildHasher, Hasher};
+
+        let mut state = self.random_state.build_hasher();
+        key.hash(&mut state);
+        let hash = state.finish();
+        return hash as usize % self.entry_array.len();
+    }
+
+    /// Attempts to find an entry or alternatively an unoccupied space for given key.
+    /// Bounded to one probe step per slot so it terminates on any table contents.
+    /// @return Entry(index) if there was such an entry, Unoccupied(index) if there was an unoccupied space, None if the hashtable is full.
+    fn find_entry_or_unoccupied<Q>(&self, key: &Q) -> FindResult
+    where K: std::borrow::Borrow<Q>, Q: std::hash::Hash + Eq + ?Sized {
+        let capacity = self.entry_array.len();
+        let hash = self.hash(key);
+        for step in 0..capacity {
+            let index = (hash + step) % capacity;
+            match &self.entry_array[index].storage {
+                &Storage::UnOccupied => return FindResult::UnOccupied(index),
+                &Storage::Occupied(ref entry) => {
+                    if entry.key.borrow() == key {
+                        return FindResult::Entry(index);
+                    }
+                },
+                &Storage::OccupiedDeleted => {},
+            }
+        }
+
+        return FindResult::None;
+    }
+
+    /// Attempts to find an entry for given key. Bounded to one probe step per slot.
+    /// @return An index of the entry if found, None otherwise
+    fn find_index_of<Q>(&self, key: &Q) -> Option<usize>
+    where K: std::borrow::Borrow<Q>, Q: std::hash::Hash + Eq + ?Sized {
+        let capacity = self.entry_array.len();
+        let hash = self.hash(key);
+        for step in 0..capacity {
+            let index = (hash + step) % capacity;
+            match &self.entry_array[index].storage {
+                &Storage::Occupied(ref entry)
+                  => { if entry.key.borrow() == key { return Some(index) } },
+                &Storage::UnOccupied => return None,
+                _ => {},
+            }
+        }
+
+        return None;
+    }
+
+    /// Removes the linking of an entry at given index. Fixes the linked
+    /// list of entries to ensure continuity.
+    fn unlink(&mut self, index: usize) {
+        let previous = self.entry_array[index].linkage.previous.clone();
+        let next = self.entry_array[index].linkage.next.clone();
+
+        if let Some(previous_index) = previous {
+            self.entry_array[previous_index].linkage.next = next;
+        }
+        else { // entry was first entry
+            self.first_index = self.entry_array[index].linkage.next;
+        }
+
+        if let Some(next_index) = next {
+            self.entry_array[next_index].linkage.previous = previous;
+        }
+        else { // entry was last entry
+            self.last_index = self.entry_array[index].linkage.previous;
+        }
+
+        self.entry_array[index].linkage.previous = None;
+        self.entry_array[index].linkage.next = None;
+    }
+
+    /// Links the entry at given index to be the new last entry.
+    fn link_as_last(&mut self, index: usize) {
+        if let Some(previous_last_index) = self.last_index {
+            self.entry_array[index].linkage.previous = Some(previous_last_index);
+            self.entry_array[previous_last_index].linkage.next = Some(index);
+        }
+        else {
+            self.entry_array[index].linkage.previous = None;
+        }
+
+        self.last_index = Some(index);
+    }
+
+    /// Overwrites an entry at given index.
+    fn insert_at_index(&mut self, index: usize, key: K, value: V) {
+        self.entry_array[index].storage = Storage::Occupied(Entry{key, value});
+        self.occupied_count += 1;
+        if self.first_index.is_none() {
+            self.first_index = Some(index);
+        }
+        self.link_as_last(index);
+    }
+
+    /// Updates the value of an entry at given index and updates the linking
+    /// to make it the new last entry.
+    fn update_at_index(&mut self, index: usize, value: V) {
+        if let &mut Storage::Occupied(ref mut entry) = &mut self.entry_array[index].storage {
+            entry.value = value;
+        }
+        self.unlink(index);
+        self.link_as_last(index);
+    }
+
+    /// Removes an entry at given index and removes its linking.
+    fn remove_at_index(&mut self, index: usize) {
+        self.unlink(index);
+        self.entry_array[index].storage = Storage::OccupiedDeleted;
+        self.occupied_count -= 1;
+    }
+
+    /// Updates a currently existing value with key equal to given key, or
+    /// alternatively creates a new entry if not yet existing.
+    /// @return Ok(()) if insertion or update was successful, Err(InsertionError) otherwise
+    pub fn insert(&mut self, key: K, value: V) -> Result<(), InsertionError> {
+        match self.find_entry_or_unoccupied(&key) {
+            FindResult::None => {
+                // A full table grows when a policy is installed, otherwise it is an error
+                let growth = match self.growth {
+                    None => return Err(InsertionError::ContainerFull{ occupied: self.occupied_count }),
+                    Some(growth) => growth,
+                };
+                let new_capacity = growth.next_capacity(self.entry_array.len());
+                // shrink_to rebuilds at exactly max(len, min_capacity) slots, which
+                // doubles as our grow primitive and drops tombstones along the way
+                self.shrink_to(new_capacity);
+                match self.find_entry_or_unoccupied(&key) {
+                    // The rebuilt table has free slots by construction
+                    FindResult::None => return Err(InsertionError::ContainerFull{ occupied: self.occupied_count }),
+                    FindResult::Entry(index) => self.update_at_index(index, value),
+                    FindResult::UnOccupied(index) => self.insert_at_index(index, key, value),
+                }
+            },
+            FindResult::Entry(index) => self.update_at_index(index, value),
+            FindResult::UnOccupied(index) => self.insert_at_index(index, key, value),
+        };
+
+        return Ok(());
+    }
+
+    /// Removes an entry with key equal to given key
+    pub fn remove<Q>(&mut self, key: &Q)
+    where K: std::borrow::Borrow<Q>, Q: std::hash::Hash + Eq + ?Sized {
+        match self.find_index_of(key) {
+            Some(index) => self.remove_at_index(index),
+            None => {},
+        };
+    }
+
+    /// Returns the value of the entry with key equal to given key.
+    /// @return None if no such entry was found, the value of the entry otherwise.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where K: std::borrow::Borrow<Q>, Q: std::hash::Hash + Eq + ?Sized {
+        let index = match self.find_index_of(key) {
+            None => return None,
+            Some(index) => index,
+        };
+
+        match &self.entry_array[index].storage {
+            &Storage::Occupied(ref entry) => return Some(&entry.value),
+            _ => return None,
+        };
+    }
+
+    /// Rebuilds the map into a fresh backing array of at most max(len, min_capacity)
+    /// slots, dropping accumulated tombstones and reclaiming memory. The linkage
+    /// is rebuilt in recency order, so get_first / get_last are unaffected.
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        // Never shrink to zero slots, or the hash's remainder is undefined
+        let new_capacity = std::cmp::max(std::cmp::max(self.occupied_count, min_capacity), 1);
+
+        // Collect the live entries in recency order by walking the linkage
+        let mut live_entries = Vec::with_capacity(self.occupied_count);
+        let mut walk_index = self.first_index;
+        while let Some(index) = walk_index {
+            walk_index = self.entry_array[index].linkage.next;
+            let storage = std::mem::replace(&mut self.entry_array[index].storage, Storage::UnOccupied);
+            if let Storage::Occupied(entry) = storage {
+                live_entries.push(entry);
+            }
+        }
+
+        // Rebuild the backing array and reinsert in order
+        self.entry_array.clear();
+        self.entry_array.resize_with(new_capacity, || { return ProbeHashMapEntry::new(); });
+        self.entry_array.shrink_to_fit();
+        self.first_index = None;
+        self.last_index = None;
+        self.occupied_count = 0;
+
+        for entry in live_entries {
+            // Every live entry fits by construction of new_capacity
+            let _ = self.insert(entry.key, entry.value);
+        }
+    }
+
+    /// @return None if the map is empty, otherwise the last added or updated entry.
+    pub fn get_last(&self) -> Option<&Entry<K, V>> {
+        let index = match &self.last_index {
+            &None => return None,
+            &Some(index) => index,
+        };
+
+        match &self.entry_array[index].storage {
+            &Storage::Occupied(ref entry) => return Some(entry),
+            _ => {
+                assert!(false, "Undefined behaviour: last_index pointed to a non-occupied entry");
+                return None;
+            },
+        };
+    }
+
+    /// @return None if the map is empty, otherwise the least recently added or updated entry.
+    pub fn get_first(&self) -> Option<&Entry<K, V>> {
+        let index = match &self.first_index {
+            &None => return None,
+            &Some(index) => index,
+        };
+
+        match &self.entry_array[index].storage {
+            &Storage::Occupied(ref entry) => return Some(entry),
+            _ => {
+                assert!(false, "Undefined behaviour: first_index pointed to a non-occupied entry");
+                return None;
+            },
+        };
+    }
+}
@@ -0,0 +1,8 @@
+pub mod probe_hash_map;
+pub mod dyn_probe_hash_map;
+pub mod shared_probe_hash_map;
+pub use probe_hash_map::ProbeHashMap;
+pub use probe_hash_map::{MultiplicativeState, MultiplicativeHasher};
+pub use probe_hash_map::ProbeStrategy;
+pub use dyn_probe_hash_map::DynProbeHashMap;
+pub use shared_probe_hash_map::SharedProbeHashMap;
@@ -13,8 +13,7 @@
 // This way we an support O(1) fetch of first and last elements
 
 
-mod probe_hash_map;
-use probe_hash_map::ProbeHashMap;
+use sample_Q1::ProbeHashMap;
 
 
 fn main() {
@@ -46,12 +45,57 @@ fn main() {
     });
 
     println!("Finished insertion of {} word entries.", count);
+
+    // A second pass showcasing the map on an aggregation workload: count how
+    // often each word occurs instead of overwriting with the latest index
+    let frequency_map: ProbeHashMap<String, u32, 100000> = count_word_frequencies(&file);
+
+    match frequency_map.get("the") {
+        Some(occurrences) => println!("The word \"the\" occurs {} times.", occurrences),
+        None => println!("The word \"the\" does not occur at all."),
+    }
+}
+
+/// Normalizes a raw whitespace-separated token into a counting key by trimming
+/// leading and trailing punctuation and lowercasing, so that "The," and "the"
+/// count as the same word
+/// @return The normalized word, possibly empty for pure punctuation tokens
+fn normalize_word(word: &str) -> String {
+    return word
+        .trim_matches(|character: char| { return !character.is_alphanumeric(); })
+        .to_lowercase();
+}
+
+/// Counts the occurrences of every whitespace-separated word of the given text,
+/// normalized via normalize_word
+/// @return A map from word to its number of occurrences
+fn count_word_frequencies<const Size: usize>(text: &str) -> ProbeHashMap<String, u32, Size> {
+    let mut frequency_map: ProbeHashMap<String, u32, Size> = ProbeHashMap::new();
+
+    text.split_whitespace().for_each(|word| {
+        let word = normalize_word(word);
+        if word.is_empty() {
+            // A token that was nothing but punctuation is not a word
+            return;
+        }
+        match frequency_map.get_or_insert_with(word.clone(), || { return 0; }) {
+            Ok(occurrences) => *occurrences += 1,
+            Err(insertion_error) => {
+                println!("Error at counting of word {}: {}", word, insertion_error);
+            }
+        }
+    });
+
+    return frequency_map;
 }
 
 
 #[cfg(test)]
 mod tests {
-    use crate::ProbeHashMap;
+    use sample_Q1::ProbeHashMap;
+    use crate::count_word_frequencies;
+    use crate::normalize_word;
+    use sample_Q1::probe_hash_map::MapEntry;
 
     // A nifty little macro that allows us to write one-line asserts
     macro_rules! matches(
@@ -92,6 +136,728 @@ mod tests {
         assert!(matches!(hash_map.get("abc"), None));
     }
     
+    #[test]
+    fn get_mut_works() {
+        let mut hash_map: ProbeHashMap<String, i32, 200> = ProbeHashMap::new();
+
+        assert!(hash_map.insert(String::from("abc"), 5).is_ok());
+        match hash_map.get_mut("abc") {
+            Some(value) => *value = 7,
+            None => assert!(false, "get_mut did not find the entry"),
+        }
+
+        assert!(matches!(hash_map.get("abc"), Some(7)));
+        assert!(matches!(hash_map.get_mut("bcd"), None));
+    }
+
+    #[test]
+    fn counting_word_frequencies_works() {
+        let text = "the cat and the dog and the bird";
+        let frequency_map: ProbeHashMap<String, u32, 200> = count_word_frequencies(text);
+
+        assert!(matches!(frequency_map.get("the"), Some(3)));
+        assert!(matches!(frequency_map.get("and"), Some(2)));
+        assert!(matches!(frequency_map.get("cat"), Some(1)));
+        assert!(matches!(frequency_map.get("fish"), None));
+    }
+
+    #[test]
+    fn normalize_word_works() {
+        assert_eq!(normalize_word("The,"), "the");
+        assert_eq!(normalize_word("the"), "the");
+        assert_eq!(normalize_word("\"Wonderful!\""), "wonderful");
+        assert_eq!(normalize_word("---"), "");
+    }
+
+    #[test]
+    fn normalized_variants_count_together() {
+        let text = "The, cat saw the dog; the \"cat\" ran";
+        let frequency_map: ProbeHashMap<String, u32, 200> = count_word_frequencies(text);
+
+        assert!(matches!(frequency_map.get("the"), Some(3)));
+        assert!(matches!(frequency_map.get("cat"), Some(2)));
+        assert!(matches!(frequency_map.get("The,"), None));
+    }
+
+    #[test]
+    fn entry_or_insert_works() {
+        let mut hash_map: ProbeHashMap<String, u32, 200> = ProbeHashMap::new();
+
+        // The first entry call inserts, the second finds the existing entry
+        for _ in 0..2 {
+            match hash_map.entry(String::from("abc")) {
+                Ok(entry) => *entry.or_insert(0) += 1,
+                Err(insertion_error) => assert!(false, "entry produced an error: {}", insertion_error),
+            }
+        }
+
+        assert!(matches!(hash_map.get("abc"), Some(2)));
+    }
+
+    #[test]
+    fn entry_distinguishes_occupied_and_vacant() {
+        let mut hash_map: ProbeHashMap<String, u32, 200> = ProbeHashMap::new();
+
+        assert!(matches!(hash_map.entry(String::from("abc")), Ok(MapEntry::Vacant(_))));
+        assert!(hash_map.insert(String::from("abc"), 5).is_ok());
+        match hash_map.entry(String::from("abc")) {
+            Ok(MapEntry::Occupied(mut occupied)) => *occupied.get_mut() = 7,
+            _ => assert!(false, "The entry should be occupied after insertion"),
+        }
+
+        assert!(matches!(hash_map.get("abc"), Some(7)));
+    }
+
+    #[test]
+    fn insert_many_returns_the_overflow() {
+        let mut hash_map: ProbeHashMap<String, u32, 2> = ProbeHashMap::new();
+
+        let pairs = vec![
+            (String::from("abc"), 1),
+            (String::from("bcd"), 2),
+            (String::from("cdf"), 3),
+            (String::from("dfe"), 4),
+        ];
+        let rejected = hash_map.insert_many(pairs);
+
+        // The first two pairs fill the container, the remaining two come back
+        assert_eq!(rejected.len(), 2);
+        assert_eq!(rejected[0], (String::from("cdf"), 3));
+        assert_eq!(rejected[1], (String::from("dfe"), 4));
+        assert!(matches!(hash_map.get("abc"), Some(1)));
+        assert!(matches!(hash_map.get("bcd"), Some(2)));
+
+        // Updates of existing keys never count as overflow
+        let updates = vec![(String::from("abc"), 10)];
+        assert!(hash_map.insert_many(updates).is_empty());
+        assert!(matches!(hash_map.get("abc"), Some(10)));
+    }
+
+    #[test]
+    fn a_table_of_tombstones_terminates() {
+        use sample_Q1::probe_hash_map::InsertionError;
+
+        let mut hash_map: ProbeHashMap<String, u32, 2> = ProbeHashMap::new();
+
+        // Fill the container completely, then delete everything: every slot is
+        // now a tombstone and probing must still terminate
+        assert!(hash_map.insert(String::from("abc"), 1).is_ok());
+        assert!(hash_map.insert(String::from("bcd"), 2).is_ok());
+        hash_map.remove("abc");
+        hash_map.remove("bcd");
+
+        assert!(matches!(hash_map.get("abc"), None));
+        assert!(matches!(hash_map.get("cdf"), None));
+        assert!(matches!(hash_map.insert(String::from("cdf"), 3), Err(InsertionError::ContainerFull{..})));
+    }
+
+    #[test]
+    fn shrink_to_keeps_the_survivors() {
+        use sample_Q1::dyn_probe_hash_map::DynProbeHashMap;
+
+        let mut hash_map: DynProbeHashMap<String, u32> = DynProbeHashMap::with_capacity(100);
+
+        for index in 0..50 {
+            assert!(hash_map.insert(format!("key{}", index), index).is_ok());
+        }
+        for index in 3..50 {
+            hash_map.remove(format!("key{}", index).as_str());
+        }
+        assert_eq!(hash_map.len(), 3);
+
+        hash_map.shrink_to(10);
+
+        assert_eq!(hash_map.capacity(), 10);
+        assert!(matches!(hash_map.get("key0"), Some(0)));
+        assert!(matches!(hash_map.get("key1"), Some(1)));
+        assert!(matches!(hash_map.get("key2"), Some(2)));
+        assert!(matches!(hash_map.get("key3"), None));
+
+        // The recency linkage survives the rebuild
+        assert!(matches!(hash_map.get_first().unwrap().key.as_str(), "key0"));
+        assert!(matches!(hash_map.get_last().unwrap().key.as_str(), "key2"));
+
+        // Shrinking below the live count keeps every entry
+        hash_map.shrink_to(1);
+        assert_eq!(hash_map.capacity(), 3);
+        assert!(matches!(hash_map.get("key1"), Some(1)));
+    }
+
+    #[test]
+    fn custom_key_equality_applies_on_insert() {
+        // With a single slot, hashing is irrelevant and the equality function
+        // alone decides whether an insert updates or overflows
+        let mut hash_map: ProbeHashMap<String, u32, 1> = ProbeHashMap::with_key_equality(
+            |left, right| { return left.trim_end() == right.trim_end(); }
+        );
+
+        assert!(hash_map.insert(String::from("abc "), 5).is_ok());
+        // Treated as the same key: this updates instead of filling the container
+        assert!(hash_map.insert(String::from("abc"), 10).is_ok());
+
+        assert!(matches!(hash_map.get("abc "), Some(10)));
+    }
+
+    #[test]
+    fn container_full_reports_the_occupied_count() {
+        use sample_Q1::probe_hash_map::InsertionError;
+
+        let mut hash_map: ProbeHashMap<String, u32, 2> = ProbeHashMap::new();
+
+        assert!(hash_map.insert(String::from("abc"), 1).is_ok());
+        assert!(hash_map.insert(String::from("bcd"), 2).is_ok());
+        assert_eq!(hash_map.len(), 2);
+
+        match hash_map.insert(String::from("cdf"), 3) {
+            Err(InsertionError::ContainerFull{ occupied }) => assert_eq!(occupied, 2),
+            Ok(()) => assert!(false, "Insertion into a full container succeeded"),
+        }
+
+        // Removals shrink the reported occupancy
+        hash_map.remove("abc");
+        assert_eq!(hash_map.len(), 1);
+    }
+
+    #[test]
+    fn can_fit_tracks_remaining_capacity() {
+        let mut hash_map: ProbeHashMap<String, u32, 2> = ProbeHashMap::new();
+
+        assert!(hash_map.can_fit(2));
+        assert!(!hash_map.can_fit(3));
+
+        assert!(hash_map.insert(String::from("abc"), 1).is_ok());
+        assert!(hash_map.can_fit(1));
+        assert!(!hash_map.can_fit(2));
+
+        assert!(hash_map.insert(String::from("bcd"), 2).is_ok());
+        assert!(hash_map.can_fit(0));
+        assert!(!hash_map.can_fit(1));
+
+        // A tombstoned slot is never reclaimed by the probe, so it still counts as used
+        hash_map.remove("abc");
+        assert!(!hash_map.can_fit(1));
+    }
+
+    #[test]
+    fn swapping_values_leaves_the_order_alone() {
+        let mut hash_map = ProbeHashMap::<String, u32, 8>::new();
+        assert!(matches!(hash_map.insert(String::from("abc"), 1), Ok(())));
+        assert!(matches!(hash_map.insert(String::from("bcd"), 2), Ok(())));
+
+        assert!(hash_map.swap_values("abc", "bcd"));
+        assert_eq!(hash_map.get("abc"), Some(&2));
+        assert_eq!(hash_map.get("bcd"), Some(&1));
+        // Recency is untouched: bcd is still the most recent entry
+        assert_eq!(hash_map.get_last().map(|entry| { return &entry.key; }), Some(&String::from("bcd")));
+
+        // A missing key leaves everything unchanged
+        assert!(!hash_map.swap_values("abc", "cde"));
+        assert_eq!(hash_map.get("abc"), Some(&2));
+    }
+
+    #[test]
+    fn renaming_a_key_keeps_value_and_position() {
+        let mut hash_map = ProbeHashMap::<String, u32, 8>::new();
+        assert!(matches!(hash_map.insert(String::from("abc"), 1), Ok(())));
+        assert!(matches!(hash_map.insert(String::from("bcd"), 2), Ok(())));
+        assert!(matches!(hash_map.insert(String::from("cde"), 3), Ok(())));
+
+        assert!(hash_map.rename_key("bcd", String::from("xyz")));
+        assert_eq!(hash_map.get("bcd"), None);
+        assert_eq!(hash_map.get("xyz"), Some(&2));
+        // The renamed entry keeps its place in the recency order
+        assert_eq!(hash_map.get_last().map(|entry| { return &entry.key; }), Some(&String::from("cde")));
+        assert_eq!(hash_map.get_first().map(|entry| { return &entry.key; }), Some(&String::from("abc")));
+
+        // Renaming onto an existing key changes nothing
+        assert!(!hash_map.rename_key("abc", String::from("cde")));
+        assert_eq!(hash_map.get("abc"), Some(&1));
+        assert_eq!(hash_map.get("cde"), Some(&3));
+    }
+
+    #[test]
+    fn order_walks_from_least_to_most_recent() {
+        let mut hash_map = ProbeHashMap::<String, u32, 8>::new();
+        assert!(matches!(hash_map.insert(String::from("a"), 1), Ok(())));
+        assert!(matches!(hash_map.insert(String::from("b"), 2), Ok(())));
+        assert!(matches!(hash_map.insert(String::from("c"), 3), Ok(())));
+        // Updating a moves it to the back of the order
+        assert!(matches!(hash_map.insert(String::from("a"), 4), Ok(())));
+
+        let order = hash_map.order();
+        assert_eq!(order, vec!["b", "c", "a"]);
+    }
+
+    #[test]
+    fn a_shared_map_serves_reads_from_many_threads() {
+        use sample_Q1::shared_probe_hash_map::SharedProbeHashMap;
+
+        let mut hash_map = ProbeHashMap::<String, u32, 16>::new();
+        assert!(matches!(hash_map.insert(String::from("a"), 1), Ok(())));
+        assert!(matches!(hash_map.insert(String::from("b"), 2), Ok(())));
+        assert!(matches!(hash_map.insert(String::from("c"), 3), Ok(())));
+
+        let shared = SharedProbeHashMap::new(hash_map);
+        let mut handles = Vec::new();
+        for _ in 0..4 {
+            let shared = shared.clone();
+            handles.push(std::thread::spawn(move || {
+                assert_eq!(shared.get("a"), Some(&1));
+                assert_eq!(shared.get("b"), Some(&2));
+                assert_eq!(shared.get("c"), Some(&3));
+                assert!(shared.contains_key("c"));
+                assert!(!shared.contains_key("d"));
+                assert_eq!(shared.len(), 3);
+            }));
+        }
+        for handle in handles {
+            assert!(handle.join().is_ok());
+        }
+    }
+
+    #[test]
+    fn from_pairs_checked_reports_overflow() {
+        use sample_Q1::probe_hash_map::InsertionError;
+
+        // Exactly Size distinct keys fit
+        let pairs = (0..4).map(|index| { return (index.to_string(), index); });
+        let hash_map = ProbeHashMap::<String, u32, 4>::from_pairs_checked(pairs);
+        match hash_map {
+            Ok(hash_map) => assert_eq!(hash_map.len(), 4),
+            Err(error) => assert!(false, "from_pairs_checked failed on a fitting set: {}", error),
+        }
+
+        // One more distinct key overflows
+        let pairs = (0..5).map(|index| { return (index.to_string(), index); });
+        let hash_map = ProbeHashMap::<String, u32, 4>::from_pairs_checked(pairs);
+        assert!(matches!(hash_map, Err(InsertionError::ContainerFull{ .. })));
+    }
+
+    #[test]
+    fn get_key_value_returns_the_stored_key() {
+        let mut hash_map = ProbeHashMap::<String, u32, 8>::new();
+        assert!(matches!(hash_map.insert(String::from("abc"), 1), Ok(())));
+
+        // Looked up via &str, the returned key is the stored String
+        match hash_map.get_key_value("abc") {
+            Some((key, value)) => {
+                assert_eq!(key, &String::from("abc"));
+                assert_eq!(value, &1);
+            },
+            None => assert!(false, "get_key_value did not find the entry"),
+        }
+        assert!(hash_map.get_key_value("bcd").is_none());
+    }
+
+    #[test]
+    fn a_growth_policy_rebuilds_a_full_table() {
+        use sample_Q1::dyn_probe_hash_map::{DynProbeHashMap, Growth};
+
+        let mut hash_map = DynProbeHashMap::<String, u32>::with_capacity(4);
+        hash_map.set_growth(Growth::Factor(1.5));
+
+        for index in 0..5 {
+            assert!(matches!(hash_map.insert(index.to_string(), index), Ok(())));
+        }
+
+        // The fifth insert triggered a rebuild at ceil(4 * 1.5) slots
+        assert_eq!(hash_map.capacity(), 6);
+        assert_eq!(hash_map.len(), 5);
+        assert_eq!(hash_map.get("0"), Some(&0));
+        assert_eq!(hash_map.get("4"), Some(&4));
+    }
+
+    #[test]
+    fn colliding_keys_raise_the_collision_counter() {
+        let mut hash_map = ProbeHashMap::<String, u32, 2>::new();
+        // The first insert into an empty table finds its ideal bucket directly
+        assert!(matches!(hash_map.insert(String::from("a"), 1), Ok(())));
+        assert_eq!(hash_map.collisions(), 0);
+
+        // Once the table is full, a further distinct key must step past
+        // occupied buckets whatever the hashes come out as
+        assert!(matches!(hash_map.insert(String::from("b"), 2), Ok(())));
+        assert!(matches!(hash_map.insert(String::from("c"), 3), Err(_)));
+        assert!(hash_map.collisions() > 0);
+    }
+
+    #[test]
+    fn value_extremes_are_found() {
+        let mut hash_map = ProbeHashMap::<String, u32, 8>::new();
+        assert!(hash_map.max_by_value().is_none());
+
+        assert!(matches!(hash_map.insert(String::from("the"), 12), Ok(())));
+        assert!(matches!(hash_map.insert(String::from("quick"), 3), Ok(())));
+        assert!(matches!(hash_map.insert(String::from("fox"), 7), Ok(())));
+
+        assert_eq!(hash_map.max_by_value(), Some((&String::from("the"), &12)));
+        assert_eq!(hash_map.min_by_value(), Some((&String::from("quick"), &3)));
+    }
+
+    #[test]
+    fn get_or_default_copies_the_value_out() {
+        let mut hash_map = ProbeHashMap::<String, u32, 8>::new();
+        assert!(matches!(hash_map.insert(String::from("abc"), 7), Ok(())));
+
+        assert_eq!(hash_map.get_or_default("abc"), 7);
+        assert_eq!(hash_map.get_or_default("bcd"), 0);
+    }
+
+    #[test]
+    fn remove_all_reports_what_was_removed() {
+        let mut hash_map = ProbeHashMap::<String, u32, 8>::new();
+        assert!(matches!(hash_map.insert(String::from("a"), 1), Ok(())));
+        assert!(matches!(hash_map.insert(String::from("b"), 2), Ok(())));
+
+        // A plain remove hands the old value back now
+        assert_eq!(hash_map.remove("a"), Some(1));
+        assert!(matches!(hash_map.insert(String::from("a"), 3), Ok(())));
+
+        let removed = hash_map.remove_all(["a", "missing", "b"]);
+        assert_eq!(removed, vec![Some(3), None, Some(2)]);
+        assert!(hash_map.is_empty());
+    }
+
+    #[test]
+    fn get_or_insert_keeps_existing_values() {
+        let mut hash_map = ProbeHashMap::<String, u32, 8>::new();
+
+        match hash_map.get_or_insert(String::from("abc"), 5) {
+            Ok(value) => assert_eq!(*value, 5),
+            Err(error) => assert!(false, "get_or_insert failed: {}", error),
+        }
+        // A second call with the same key must not overwrite
+        match hash_map.get_or_insert(String::from("abc"), 9) {
+            Ok(value) => assert_eq!(*value, 5),
+            Err(error) => assert!(false, "get_or_insert failed: {}", error),
+        }
+    }
+
+    #[test]
+    fn fresh_buckets_are_all_empty() {
+        use sample_Q1::probe_hash_map::BucketState;
+
+        let hash_map = ProbeHashMap::<String, u32, 4>::new();
+        let buckets: Vec<(usize, BucketState)> = hash_map.iter_buckets().collect();
+        assert_eq!(buckets.len(), 4);
+        for (index, (bucket_index, state)) in buckets.iter().enumerate() {
+            assert_eq!(*bucket_index, index);
+            assert_eq!(*state, BucketState::Empty);
+        }
+
+        // After an insert exactly one bucket reports occupied
+        let mut hash_map = ProbeHashMap::<String, u32, 4>::new();
+        assert!(matches!(hash_map.insert(String::from("abc"), 1), Ok(())));
+        let occupied = hash_map.iter_buckets()
+            .filter(|(_, state)| { return matches!(*state, BucketState::Occupied{ .. }); })
+            .count();
+        assert_eq!(occupied, 1);
+    }
+
+    #[test]
+    fn retain_mut_decays_and_drops_in_one_pass() {
+        let mut hash_map = ProbeHashMap::<String, u32, 8>::new();
+        assert!(matches!(hash_map.insert(String::from("a"), 1), Ok(())));
+        assert!(matches!(hash_map.insert(String::from("b"), 2), Ok(())));
+        assert!(matches!(hash_map.insert(String::from("c"), 3), Ok(())));
+
+        // Decrement every counter and drop the ones that reach zero
+        hash_map.retain_mut(|_, value| {
+            *value -= 1;
+            return *value > 0;
+        });
+
+        assert_eq!(hash_map.len(), 2);
+        assert_eq!(hash_map.get("a"), None);
+        assert_eq!(hash_map.get("b"), Some(&1));
+        assert_eq!(hash_map.get("c"), Some(&2));
+        assert_eq!(hash_map.order(), vec!["b", "c"]);
+    }
+
+    #[test]
+    fn entry_or_evict_drops_the_oldest() {
+        let mut hash_map = ProbeHashMap::<String, u32, 2>::new();
+        assert_eq!(hash_map.entry_or_evict(String::from("a"), 1), None);
+        assert_eq!(hash_map.entry_or_evict(String::from("b"), 2), None);
+
+        // The table is full: the least recently used pair has to make way
+        let evicted = hash_map.entry_or_evict(String::from("c"), 3);
+        assert_eq!(evicted, Some((String::from("a"), 1)));
+        assert_eq!(hash_map.get("b"), Some(&2));
+        assert_eq!(hash_map.get("c"), Some(&3));
+        assert_eq!(hash_map.len(), 2);
+
+        // An update of an existing key never evicts
+        assert_eq!(hash_map.entry_or_evict(String::from("b"), 9), None);
+        assert_eq!(hash_map.get("b"), Some(&9));
+
+        // A warm cache keeps cycling: every new key evicts exactly one entry
+        // and tombstones never starve the table
+        for round in 0..100u32 {
+            let evicted = hash_map.entry_or_evict(format!("key-{}", round), round);
+            assert!(evicted.is_some());
+            assert_eq!(hash_map.len(), 2);
+        }
+        assert_eq!(hash_map.get("key-99"), Some(&99));
+    }
+
+    #[test]
+    fn would_overflow_predicts_batch_fit() {
+        let mut hash_map = ProbeHashMap::<String, u32, 4>::new();
+        assert!(matches!(hash_map.insert(String::from("a"), 1), Ok(())));
+        assert!(matches!(hash_map.insert(String::from("b"), 2), Ok(())));
+
+        // Two new keys, one duplicate within the batch, one already present: fits
+        let fitting = vec![String::from("c"), String::from("c"), String::from("a"), String::from("d")];
+        assert!(!hash_map.would_overflow(&fitting));
+
+        // Three genuinely new keys exceed the two remaining slots
+        let overflowing = vec![String::from("c"), String::from("d"), String::from("e")];
+        assert!(hash_map.would_overflow(&overflowing));
+    }
+
+    #[test]
+    fn merging_resolves_collisions_with_the_resolver() {
+        use sample_Q1::probe_hash_map::InsertionError;
+
+        let mut counts = ProbeHashMap::<String, u64, 8>::new();
+        assert!(matches!(counts.insert(String::from("the"), 3), Ok(())));
+        assert!(matches!(counts.insert(String::from("quick"), 1), Ok(())));
+
+        let mut shard = ProbeHashMap::<String, u64, 8>::new();
+        assert!(matches!(shard.insert(String::from("the"), 4), Ok(())));
+        assert!(matches!(shard.insert(String::from("fox"), 2), Ok(())));
+
+        assert!(matches!(counts.merge(shard, |existing, incoming| { return existing + incoming; }), Ok(())));
+        assert_eq!(counts.len(), 3);
+        assert!(matches!(counts.get("the"), Some(&7)));
+        assert!(matches!(counts.get("quick"), Some(&1)));
+        assert!(matches!(counts.get("fox"), Some(&2)));
+
+        // A union beyond capacity is refused before anything is written
+        let mut small = ProbeHashMap::<u64, u64, 2>::new();
+        assert!(matches!(small.insert(1, 1), Ok(())));
+        assert!(matches!(small.insert(2, 2), Ok(())));
+        let mut overflow = ProbeHashMap::<u64, u64, 2>::new();
+        assert!(matches!(overflow.insert(3, 3), Ok(())));
+        assert!(matches!(small.merge(overflow, |_, incoming| { return incoming; }),
+            Err(InsertionError::ContainerFull{ occupied: 2 })));
+        assert!(matches!(small.get(&1), Some(&1)));
+    }
+
+    #[test]
+    fn values_sorted_puts_the_largest_first() {
+        let mut hash_map = ProbeHashMap::<String, u64, 8>::new();
+        assert!(matches!(hash_map.insert(String::from("the"), 12), Ok(())));
+        assert!(matches!(hash_map.insert(String::from("quick"), 3), Ok(())));
+        assert!(matches!(hash_map.insert(String::from("brown"), 7), Ok(())));
+        assert!(matches!(hash_map.insert(String::from("fox"), 3), Ok(())));
+
+        let sorted = hash_map.values_sorted();
+        assert_eq!(sorted.len(), 4);
+        assert_eq!(sorted[0], (&String::from("the"), &12));
+        assert_eq!(sorted[1], (&String::from("brown"), &7));
+        // The stable sort keeps equal values in recency order
+        assert_eq!(sorted[2], (&String::from("quick"), &3));
+        assert_eq!(sorted[3], (&String::from("fox"), &3));
+    }
+
+    #[test]
+    fn the_multiplicative_hasher_behaves_like_a_map() {
+        let mut hash_map = ProbeHashMap::<u64, u64, 16, _>::with_multiplicative_hasher();
+        for key in 0..8 {
+            assert!(matches!(hash_map.insert(key, key * 10), Ok(())));
+        }
+
+        assert_eq!(hash_map.len(), 8);
+        for key in 0..8 {
+            assert_eq!(hash_map.get(&key), Some(&(key * 10)));
+        }
+
+        // Sequential keys spread without clustering: no probe collisions at all
+        assert_eq!(hash_map.collisions(), 0);
+
+        assert_eq!(hash_map.remove(&3), Some(30));
+        assert!(matches!(hash_map.get(&3), None));
+    }
+
+    #[test]
+    fn state_counts_tally_every_slot() {
+        let mut hash_map = ProbeHashMap::<String, u64, 8>::new();
+        assert_eq!(hash_map.state_counts(), (0, 0, 8));
+
+        assert!(matches!(hash_map.insert(String::from("abc"), 1), Ok(())));
+        assert!(matches!(hash_map.insert(String::from("bcd"), 2), Ok(())));
+        assert!(matches!(hash_map.insert(String::from("cde"), 3), Ok(())));
+        assert_eq!(hash_map.remove("bcd"), Some(2));
+
+        let (occupied, deleted, empty) = hash_map.state_counts();
+        assert_eq!(occupied, 2);
+        assert_eq!(deleted, 1);
+        assert_eq!(empty, 5);
+        assert_eq!(occupied + deleted + empty, 8);
+    }
+
+    #[test]
+    fn bidirectional_probing_covers_the_whole_table() {
+        use sample_Q1::ProbeStrategy;
+
+        // Fill a small table completely, so every probe path gets exercised
+        let mut hash_map = ProbeHashMap::<u64, u64, 8>::with_probe_strategy(ProbeStrategy::Bidirectional);
+        for key in 0..8 {
+            assert!(matches!(hash_map.insert(key, key * 2), Ok(())));
+        }
+        assert_eq!(hash_map.len(), 8);
+        for key in 0..8 {
+            assert_eq!(hash_map.get(&key), Some(&(key * 2)));
+        }
+
+        // A ninth key finds no slot rather than looping
+        use sample_Q1::probe_hash_map::InsertionError;
+        assert!(matches!(hash_map.insert(8, 16), Err(InsertionError::ContainerFull{ occupied: 8 })));
+
+        assert_eq!(hash_map.remove(&5), Some(10));
+        assert!(matches!(hash_map.get(&5), None));
+    }
+
+    #[test]
+    fn insert_or_update_with_counts_words() {
+        let mut hash_map = ProbeHashMap::<String, u64, 8>::new();
+        let words = ["the", "quick", "the", "fox", "the", "quick"];
+        for word in words {
+            assert!(matches!(
+                hash_map.insert_or_update_with(String::from(word), 1, |count| { *count += 1; }),
+                Ok(())));
+        }
+
+        assert_eq!(hash_map.len(), 3);
+        assert!(matches!(hash_map.get("the"), Some(&3)));
+        assert!(matches!(hash_map.get("quick"), Some(&2)));
+        assert!(matches!(hash_map.get("fox"), Some(&1)));
+
+        // Updates do not reorder: "the" stays the least recently inserted key
+        assert_eq!(hash_map.first_key_value(), Some((&String::from("the"), &3)));
+    }
+
+    #[test]
+    fn collision_groups_expose_contested_buckets() {
+        // The multiplicative hasher is deterministic: for Size 8 a key lands in
+        // bucket (5 * key) % 8, so keys 8 apart share a bucket by construction
+        let mut hash_map = ProbeHashMap::<u64, u64, 8, _>::with_multiplicative_hasher();
+        assert!(matches!(hash_map.insert(0, 0), Ok(())));
+        assert!(matches!(hash_map.insert(8, 1), Ok(())));
+        assert!(matches!(hash_map.insert(16, 2), Ok(())));
+        assert!(matches!(hash_map.insert(1, 3), Ok(())));
+
+        let groups = hash_map.collision_groups();
+        assert_eq!(groups.len(), 1);
+        let mut group: Vec<u64> = groups[0].iter().map(|key| { return **key; }).collect();
+        group.sort();
+        assert_eq!(group, vec![0, 8, 16]);
+
+        // An uncontested table reports no groups at all
+        let mut hash_map = ProbeHashMap::<u64, u64, 8, _>::with_multiplicative_hasher();
+        assert!(matches!(hash_map.insert(0, 0), Ok(())));
+        assert!(matches!(hash_map.insert(1, 1), Ok(())));
+        assert!(hash_map.collision_groups().is_empty());
+    }
+
+    #[test]
+    fn get_mut_or_insert_reports_creation() {
+        let mut hash_map = ProbeHashMap::<String, u64, 8>::new();
+
+        let (value, inserted) = hash_map.get_mut_or_insert(String::from("abc"), 1).unwrap();
+        assert_eq!(*value, 1);
+        assert!(inserted);
+        *value += 10;
+
+        // The second call finds the entry and leaves it untouched
+        let (value, inserted) = hash_map.get_mut_or_insert(String::from("abc"), 1).unwrap();
+        assert_eq!(*value, 11);
+        assert!(!inserted);
+        assert_eq!(hash_map.len(), 1);
+    }
+
+    #[test]
+    fn from_vec_last_wins_keeps_the_later_duplicates() {
+        let pairs = vec![
+            (String::from("abc"), 1),
+            (String::from("bcd"), 2),
+            (String::from("abc"), 3),
+        ];
+        let hash_map = ProbeHashMap::<String, u64, 8>::from_vec_last_wins(pairs).unwrap();
+        assert_eq!(hash_map.len(), 2);
+        assert!(matches!(hash_map.get("abc"), Some(&3)));
+        assert!(matches!(hash_map.get("bcd"), Some(&2)));
+
+        // Distinct keys beyond Size are an error, duplicates are not
+        use sample_Q1::probe_hash_map::InsertionError;
+        let pairs = vec![(1, 1), (2, 2), (1, 10)];
+        let hash_map = ProbeHashMap::<u64, u64, 2>::from_vec_last_wins(pairs).unwrap();
+        assert!(matches!(hash_map.get(&1), Some(&10)));
+        let pairs = vec![(1, 1), (2, 2), (3, 3)];
+        assert!(matches!(ProbeHashMap::<u64, u64, 2>::from_vec_last_wins(pairs),
+            Err(InsertionError::ContainerFull{ .. })));
+    }
+
+    #[test]
+    fn taking_an_entry_returns_key_and_value() {
+        let mut hash_map = ProbeHashMap::<String, u64, 8>::new();
+        assert!(matches!(hash_map.insert(String::from("abc"), 1), Ok(())));
+        assert!(matches!(hash_map.insert(String::from("bcd"), 2), Ok(())));
+
+        let (key, value) = hash_map.take("abc").unwrap();
+        assert_eq!(key, "abc");
+        assert_eq!(value, 1);
+        assert!(matches!(hash_map.get("abc"), None));
+        assert_eq!(hash_map.len(), 1);
+
+        // The owned key can move straight into another map
+        let mut other = ProbeHashMap::<String, u64, 8>::new();
+        assert!(matches!(other.insert(key, value), Ok(())));
+        assert!(matches!(other.get("abc"), Some(&1)));
+
+        // A missing key takes nothing
+        assert!(matches!(hash_map.take("abc"), None));
+    }
+
+    #[test]
+    fn shrinking_an_emptied_map_keeps_it_usable() {
+        use sample_Q1::dyn_probe_hash_map::DynProbeHashMap;
+
+        let mut hash_map = DynProbeHashMap::<String, u64>::with_capacity(4);
+        assert!(matches!(hash_map.insert(String::from("abc"), 1), Ok(())));
+        hash_map.remove("abc");
+
+        // Reclaiming all memory must not produce a zero-slot table
+        hash_map.shrink_to(0);
+        assert_eq!(hash_map.capacity(), 1);
+        assert!(matches!(hash_map.insert(String::from("bcd"), 2), Ok(())));
+        assert!(matches!(hash_map.get("bcd"), Some(&2)));
+
+        // The same floor applies at construction
+        let mut hash_map = DynProbeHashMap::<String, u64>::with_capacity(0);
+        assert_eq!(hash_map.capacity(), 1);
+        assert!(matches!(hash_map.insert(String::from("abc"), 1), Ok(())));
+        assert!(matches!(hash_map.get("abc"), Some(&1)));
+    }
+
+    #[test]
+    fn key_value_tuples_work() {
+        let mut hash_map: ProbeHashMap<String, i32, 200> = ProbeHashMap::new();
+
+        assert!(matches!(hash_map.first_key_value(), None));
+        assert!(matches!(hash_map.last_key_value(), None));
+
+        assert!(hash_map.insert(String::from("abc"), 5).is_ok());
+        assert_eq!(hash_map.first_key_value(), Some((&String::from("abc"), &5)));
+        assert_eq!(hash_map.last_key_value(), Some((&String::from("abc"), &5)));
+
+        assert!(hash_map.insert(String::from("bcd"), 10).is_ok());
+        assert_eq!(hash_map.first_key_value(), Some((&String::from("abc"), &5)));
+        assert_eq!(hash_map.last_key_value(), Some((&String::from("bcd"), &10)));
+    }
+
     #[test]
     fn get_first_works() {
         let mut hash_map: ProbeHashMap<String, i32, 200> = ProbeHashMap::new();
@@ -9,33 +9,33 @@ pub struct Entry<K, V> {
 
 // Then we use a Storage struct that points to the previous and next element
 // by the keys of either.
-enum Storage<K, V> {
+pub(crate) enum Storage<K, V> {
     UnOccupied,
     Occupied(Entry<K, V>),
     OccupiedDeleted,
 }
-struct Linkage {
-    previous: Option<usize>,
-    next: Option<usize>,
+pub(crate) struct Linkage {
+    pub(crate) previous: Option<usize>,
+    pub(crate) next: Option<usize>,
 }
-struct ProbeHashMapEntry<K, V> {
-    storage: Storage<K,V>,
-    linkage: Linkage,
+pub(crate) struct ProbeHashMapEntry<K, V> {
+    pub(crate) storage: Storage<K,V>,
+    pub(crate) linkage: Linkage,
 }
 
 /// Since we are using a fixed size hashtable, it can become full
 /// In this case we want to return an error on trying to insert entries
 #[derive(Debug)]
 pub enum InsertionError {
-    ContainerFull,
+    ContainerFull{ occupied: usize }, // Carries the number of live entries at the time of failure
 }
 
 // Pretty printing for our InsertionError
 impl std::fmt::Display for InsertionError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self {
-            &InsertionError::ContainerFull => {
-                write!(f, "The container is full.")
+            &InsertionError::ContainerFull{ ref occupied } => {
+                write!(f, "The container is full, holding {} live entries.", occupied)
             },
         }
     }
@@ -45,15 +45,20 @@ impl std::fmt::Display for InsertionError {
 // and implement a standard hash table that utilizes this array as its storage.
 // As a Hasher we are using the standard hasher and modify the result as the 
 // remainder of our Size.
-pub struct ProbeHashMap<K, V, const Size: usize> {
-    random_state: std::hash::RandomState, // Use the standard hasher
+pub struct ProbeHashMap<K, V, const Size: usize, S: std::hash::BuildHasher = std::hash::RandomState> {
+    build_hasher: S, // The hasher factory; RandomState unless a specialized one was supplied
     first_index: Option<usize>, // Key to least recent key-value pair inserted / updated
     last_index: Option<usize>, // Key to most recent key-value pair inserted / updated
+    key_eq: Option<fn(&K, &K) -> bool>, // An optional domain equality used instead of Eq where a full key is at hand
+    occupied_count: usize, // Number of live entries, excluding deleted ones
+    deleted_count: usize, // Number of tombstoned slots, which the probe never reclaims
+    collision_count: std::sync::atomic::AtomicU64, // Probe steps taken beyond the ideal bucket, for hasher evaluation
+    probe_strategy: ProbeStrategy, // How probe steps translate into slot offsets
     entry_array: Vec<ProbeHashMapEntry<K, V>>,
 }
 
 impl<K, V> ProbeHashMapEntry<K, V> {
-    pub fn new() -> Self {
+    pub(crate) fn new() -> Self {
         ProbeHashMapEntry { 
             storage: Storage::UnOccupied, 
             linkage: Linkage { 
@@ -66,139 +71,220 @@ impl<K, V> ProbeHashMapEntry<K, V> {
 
 impl<K, V, const Size: usize> ProbeHashMap<K, V, Size> {
     pub fn new() -> Self {
+        return Self::with_hasher(std::hash::RandomState::new());
+    }
+}
+
+impl<K, V, const Size: usize, S: std::hash::BuildHasher> ProbeHashMap<K, V, Size, S> {
+    /// Creates a map that hashes its keys with the given hasher factory instead
+    /// of the standard RandomState, for workloads where a specialized hasher
+    /// such as MultiplicativeState measurably beats SipHash.
+    pub fn with_hasher(build_hasher: S) -> Self {
         // Allocate vector with capacity in mind to avoid resizing
         let mut entry_array = Vec::with_capacity(Size);
         entry_array.resize_with(Size, || { return ProbeHashMapEntry::new(); });
         ProbeHashMap {
-            random_state: std::hash::RandomState::new(),
+            build_hasher,
             first_index: None,
             last_index: None,
+            key_eq: None,
+            occupied_count: 0,
+            deleted_count: 0,
+            collision_count: std::sync::atomic::AtomicU64::new(0),
+            probe_strategy: ProbeStrategy::Forward,
             entry_array,
         }
     }
+
+    /// @return The number of live entries currently stored
+    pub fn len(&self) -> usize {
+        return self.occupied_count;
+    }
+
+    /// @return Whether the map stores no live entries
+    pub fn is_empty(&self) -> bool {
+        return self.occupied_count == 0;
+    }
+
+    /// Tallies the slots by state in a single pass over the storage, for
+    /// periodic health logging of a long-lived map. Cheaper and more telling
+    /// than separate len() and tombstone queries.
+    /// @return The (occupied, deleted, empty) slot counts, summing to Size
+    pub fn state_counts(&self) -> (usize, usize, usize) {
+        let mut occupied = 0;
+        let mut deleted = 0;
+        let mut empty = 0;
+        for entry in &self.entry_array {
+            match &entry.storage {
+                &Storage::Occupied(_) => occupied += 1,
+                &Storage::OccupiedDeleted => deleted += 1,
+                &Storage::UnOccupied => empty += 1,
+            }
+        }
+        return (occupied, deleted, empty);
+    }
+
+    /// Checks whether the given number of additional distinct keys can fit.
+    /// Deleted slots count as used here: the probe never reclaims a tombstone,
+    /// so only slots that were never occupied can take new entries.
+    /// @return Whether the additional keys stay within the remaining capacity
+    pub fn can_fit(&self, additional: usize) -> bool {
+        return self.occupied_count + self.deleted_count + additional <= Size;
+    }
+
+}
+
+impl<K, V, const Size: usize> ProbeHashMap<K, V, Size> {
+    /// Creates a map probing with the given strategy. Bidirectional probing
+    /// spreads clusters to both sides of a hot bucket, which can shorten probe
+    /// sequences for unevenly distributed keys.
+    pub fn with_probe_strategy(probe_strategy: ProbeStrategy) -> Self {
+        let mut hash_map = Self::new();
+        hash_map.probe_strategy = probe_strategy;
+        return hash_map;
+    }
+
+    /// Creates a map that compares keys with the given function instead of Eq.
+    /// The function must be consistent with the standard hasher: keys it treats
+    /// as equal have to produce the same hash. It applies wherever a full key is
+    /// supplied (insert, entry, get_or_insert_with); borrowed lookups keep Eq.
+    pub fn with_key_equality(key_eq: fn(&K, &K) -> bool) -> Self {
+        let mut hash_map = Self::new();
+        hash_map.key_eq = Some(key_eq);
+        return hash_map;
+    }
 }
 
 // Let's define some private functions for convenience
 // For our helper functions we work with the resolution of keys, resulting hashes and indices of storage
-enum FindResult {
+// The diagnostic state of a single physical slot as reported by iter_buckets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BucketState {
+    Empty, // Never occupied; probes may stop here
+    Occupied{ ideal_bucket: usize }, // Holds a live entry whose hash points at the given bucket
+    Deleted, // A tombstone left behind by a removal
+}
+
+// How the probe walks away from a key's ideal bucket when it is taken.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProbeStrategy {
+    Forward, // The classic linear walk: +1, +2, +3, ...
+    Bidirectional, // Alternate around the ideal bucket: +1, -1, +2, -2, ...
+}
+
+pub(crate) enum FindResult {
     None,
     Entry(usize),
     UnOccupied(usize),
 }
 
-impl<K: std::hash::Hash + Eq, V, const Size: usize> ProbeHashMap<K, V, Size> {
+impl<K: std::hash::Hash + Eq, V, const Size: usize, S: std::hash::BuildHasher> ProbeHashMap<K, V, Size, S> {
     /// Calculates the hash of the given key, cropped to our storage size
     /// @return the hash of the key cropped to [0, Size - 1]
     fn hash<Q>(&self, key: &Q) -> usize
     where K: std::borrow::Borrow<Q>, Q: std::hash::Hash + Eq + ?Sized {
-        use std::hash::{BuildHasher, Hasher};
+        use std::hash::Hasher;
 
-        let mut state = self.random_state.build_hasher();
+        let mut state = self.build_hasher.build_hasher();
         key.hash(&mut state);
         let hash = state.finish();
         return hash as usize % Size;
     }
 
-    /// Attempts to find an entry or alternatively an unoccupied space for given key
-    /// @return Entry(index) if there was such an entry, Unoccupied(index) if there was an unoccupied space, None if the hashtable is full.
-    fn find_entry_or_unoccupied<Q>(&self, key: &Q) -> FindResult
-    where K: std::borrow::Borrow<Q>, Q: std::hash::Hash + Eq + ?Sized {
-        let hash = self.hash(key);
-        let mut index = hash;
-        // Possible entries from [hash, Size-1]
-        while index < Size {
-            match &self.entry_array[index].storage {
-                &Storage::UnOccupied => return FindResult::UnOccupied(index),
-                &Storage::Occupied(ref entry) => {
-                    if entry.key.borrow() == key {
-                        return FindResult::Entry(index);
-                    }
-                },
-                _ => continue,
-            }
-            index+=1;
+    /// Translates a probe step into a slot index under the configured strategy.
+    /// Both strategies visit every slot exactly once over Size steps: the
+    /// bidirectional walk maps odd steps to +(step+1)/2 and even ones to -step/2.
+    /// @return The index of the slot the given step lands on
+    fn probe_index(&self, hash: usize, step: usize) -> usize {
+        match self.probe_strategy {
+            ProbeStrategy::Forward => return (hash + step) % Size,
+            ProbeStrategy::Bidirectional => {
+                match step % 2 {
+                    1 => return (hash + (step + 1) / 2) % Size,
+                    _ => return (hash + Size - step / 2) % Size,
+                }
+            },
         }
+    }
 
-        // Possible entries from [0, hash - 1]
-        index = 0;
-        while index < hash {
+    /// Attempts to find a matching entry or alternatively an unoccupied space,
+    /// starting the probe at the given hash. The probe is bounded to Size steps
+    /// so that even a table consisting entirely of deleted entries terminates
+    /// with a clean result instead of looping.
+    /// @return Entry(index) if an entry matched, Unoccupied(index) if there was an unoccupied space, None if the hashtable is full.
+    fn find_entry_or_unoccupied_with(&self, hash: usize, matches: &dyn Fn(&K) -> bool) -> FindResult {
+        // Probe every slot at most once, wrapping around from the hash position
+        for step in 0..Size {
+            let index = self.probe_index(hash, step);
+            if step > 0 { // Every step past the ideal bucket is a collision
+                self.collision_count.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            }
             match &self.entry_array[index].storage {
                 &Storage::UnOccupied => return FindResult::UnOccupied(index),
                 &Storage::Occupied(ref entry) => {
-                    if entry.key.borrow() == key {
+                    if matches(&entry.key) {
                         return FindResult::Entry(index);
                     }
                 },
-                _ => continue,
+                &Storage::OccupiedDeleted => {},
             }
-            index+=1;
         }
 
         return FindResult::None;
     }
 
-    /// Attempts to find an entry for given key
-    /// @return An index of the entry if found, None otherwise
-    fn find_index_of<Q>(&self, key: &Q) -> Option<usize>
-    where K: std::borrow::Borrow<Q>, Q: std::hash::Hash + Eq + ?Sized {
+    /// Resolves the slot for a full key, honouring the custom key equality if set
+    /// @return Entry(index) if an entry matched, Unoccupied(index) if there was an unoccupied space, None if the hashtable is full.
+    fn find_entry_or_unoccupied_for_key(&self, key: &K) -> FindResult {
         let hash = self.hash(key);
-        let mut index = hash;
-        // Possible entries from [hash, Size-1]
-        while index < Size {
-            match &self.entry_array[index].storage {
-                &Storage::Occupied(ref entry) 
-                  => { if entry.key.borrow() == key { return Some(index) } },
-                &Storage::UnOccupied => return None,
-                _ => {},
-            }
-            index+=1;
-        }
+        match self.key_eq {
+            Some(key_eq) => return self.find_entry_or_unoccupied_with(hash, &|stored| { return key_eq(stored, key); }),
+            None => return self.find_entry_or_unoccupied_with(hash, &|stored| { return stored == key; }),
+        };
+    }
 
-        // Possible entries from [0, hash - 1]
-        index = 0;
-        while index < Size {
+    /// Attempts to find a matching entry, starting the probe at the given hash.
+    /// Bounded to Size probe steps.
+    /// @return An index of the entry if found, None otherwise
+    fn find_index_with(&self, hash: usize, matches: &dyn Fn(&K) -> bool) -> Option<usize> {
+        for step in 0..Size {
+            let index = self.probe_index(hash, step);
+            if step > 0 { // Every step past the ideal bucket is a collision
+                self.collision_count.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            }
             match &self.entry_array[index].storage {
                 &Storage::Occupied(ref entry) 
-                  => { if entry.key.borrow() == key { return Some(index) } },
+                  => { if matches(&entry.key) { return Some(index) } },
                 &Storage::UnOccupied => return None,
                 _ => {},
             }
-            index+=1;
         }
 
         return None;
     }
 
-    /// Attempts to find an entry for given key and returns it as a reference
-    /// @return A borrow to the entry if found, None otherwise
-    fn find_entry<Q>(&self, key: &Q,) -> Option<&Entry<K, V>>
+    /// Attempts to find an entry for given key. Bounded to Size probe steps.
+    /// @return An index of the entry if found, None otherwise
+    fn find_index_of<Q>(&self, key: &Q) -> Option<usize>
     where K: std::borrow::Borrow<Q>, Q: std::hash::Hash + Eq + ?Sized {
         let hash = self.hash(key);
-        let mut index = hash;
-        // Possible entries from [hash, Size-1]
-        while index < Size {
-            match &self.entry_array[index].storage {
-                &Storage::Occupied(ref entry) 
-                  => { if entry.key.borrow() == key { return Some(entry) } },
-                &Storage::UnOccupied => return None,
-                _ => {},
-            }
-            index+=1;
-        }
+        return self.find_index_with(hash, &|stored| { return stored.borrow() == key; });
+    }
 
-        // Possible entries from [0, hash - 1]
-        index = 0;
-        while index < Size {
-            match &self.entry_array[index].storage {
-                &Storage::Occupied(ref entry) 
-                  => { if entry.key.borrow() == key { return Some(entry) } },
-                &Storage::UnOccupied => return None,
-                _ => {},
-            }
-            index+=1;
-        }
+    /// Attempts to find an entry for given key and returns it as a reference.
+    /// Bounded to Size probe steps.
+    /// @return A borrow to the entry if found, None otherwise
+    fn find_entry<Q>(&self, key: &Q,) -> Option<&Entry<K, V>>
+    where K: std::borrow::Borrow<Q>, Q: std::hash::Hash + Eq + ?Sized {
+        let index = match self.find_index_of(key) {
+            None => return None,
+            Some(index) => index,
+        };
 
-        return None;
+        match &self.entry_array[index].storage {
+            &Storage::Occupied(ref entry) => return Some(entry),
+            _ => return None,
+        };
     }
 
     /// Removes the linking of an entry at given index. Fixes the linked
@@ -244,6 +330,7 @@ impl<K: std::hash::Hash + Eq, V, const Size: usize> ProbeHashMap<K, V, Size> {
     /// Overwrites an entry at given index.
     fn insert_at_index(&mut self, index: usize, key: K, value: V) {
         self.entry_array[index].storage = Storage::Occupied(Entry{key, value});
+        self.occupied_count += 1;
         if self.first_index.is_none() {
             self.first_index = Some(index);
         }
@@ -261,9 +348,19 @@ impl<K: std::hash::Hash + Eq, V, const Size: usize> ProbeHashMap<K, V, Size> {
     }
 
     /// Removes an entry at given index and removes its linking.
-    fn remove_at_index(&mut self, index: usize) {
+    /// @return The value the removed entry held
+    fn remove_at_index(&mut self, index: usize) -> Option<V> {
         self.unlink(index);
-        self.entry_array[index].storage = Storage::OccupiedDeleted;
+        let storage = std::mem::replace(&mut self.entry_array[index].storage, Storage::OccupiedDeleted);
+        self.occupied_count -= 1;
+        self.deleted_count += 1;
+        match storage {
+            Storage::Occupied(entry) => return Some(entry.value),
+            _ => {
+                assert!(false, "Undefined behaviour: removal of a non-occupied entry");
+                return None;
+            },
+        }
     }
 
     // Having defined helper functions, we define our publicly available ones:
@@ -273,8 +370,8 @@ impl<K: std::hash::Hash + Eq, V, const Size: usize> ProbeHashMap<K, V, Size> {
     /// @return Ok(()) if insertion or update was successful, Err(InsertionError) otherwise
     pub fn insert(&mut self, key: K, value: V) -> Result<(), InsertionError> {
         // Find unoccupied index starting at hash value
-        match self.find_entry_or_unoccupied(&key) {
-            FindResult::None => return Err(InsertionError::ContainerFull),
+        match self.find_entry_or_unoccupied_for_key(&key) {
+            FindResult::None => return Err(InsertionError::ContainerFull{ occupied: self.occupied_count }),
             FindResult::Entry(index) => self.update_at_index(index, value),
             FindResult::UnOccupied(index) => self.insert_at_index(index, key, value),
         };
@@ -282,13 +379,140 @@ impl<K: std::hash::Hash + Eq, V, const Size: usize> ProbeHashMap<K, V, Size> {
         return Ok(());
     }
 
+    /// Inserts every pair that fits, collecting those rejected for lack of space.
+    /// Unlike an Extend implementation this loses no data on a full container:
+    /// the caller gets the overflow back and can retry against a larger map.
+    /// @return The pairs that could not be placed, empty if everything fit
+    pub fn insert_many(&mut self, pairs: impl IntoIterator<Item = (K, V)>) -> Vec<(K, V)> {
+        let mut rejected = Vec::new();
+        for (key, value) in pairs {
+            match self.find_entry_or_unoccupied_for_key(&key) {
+                FindResult::None => rejected.push((key, value)),
+                FindResult::Entry(index) => self.update_at_index(index, value),
+                FindResult::UnOccupied(index) => self.insert_at_index(index, key, value),
+            };
+        }
+        return rejected;
+    }
+
+    /// Collects every live entry and sorts the snapshot descending by value,
+    /// so the hottest entries come first for reporting. The sort is stable:
+    /// equal values keep their recency order.
+    /// @return The live entries as key-value pairs, largest value first
+    pub fn values_sorted(&self) -> Vec<(&K, &V)>
+    where V: Ord {
+        let mut entries = Vec::with_capacity(self.occupied_count);
+        let mut walk_index = self.first_index;
+        while let Some(index) = walk_index {
+            walk_index = self.entry_array[index].linkage.next;
+            if let &Storage::Occupied(ref entry) = &self.entry_array[index].storage {
+                entries.push((&entry.key, &entry.value));
+            }
+        }
+        entries.sort_by(|a, b| { return b.1.cmp(a.1); });
+        return entries;
+    }
+
+    /// The idiomatic counter pattern in one probe: inserts the given default
+    /// when the key is absent, or applies the update function to the existing
+    /// value when present. An updated entry keeps its recency position; only a
+    /// fresh insert links as the most recent entry.
+    /// @return Ok(()) if the value was inserted or updated, Err(ContainerFull) otherwise
+    pub fn insert_or_update_with<F>(&mut self, key: K, default: V, update: F) -> Result<(), InsertionError>
+    where F: FnOnce(&mut V) {
+        match self.find_entry_or_unoccupied_for_key(&key) {
+            FindResult::None => return Err(InsertionError::ContainerFull{ occupied: self.occupied_count }),
+            FindResult::UnOccupied(index) => self.insert_at_index(index, key, default),
+            FindResult::Entry(index) => {
+                if let &mut Storage::Occupied(ref mut entry) = &mut self.entry_array[index].storage {
+                    update(&mut entry.value);
+                }
+            },
+        }
+
+        return Ok(());
+    }
+
+    /// Merges every entry of the given map into this one, consuming it. A key
+    /// present in both maps keeps a single entry whose value comes from
+    /// resolve(existing, incoming); keys only found in the other map insert as
+    /// usual. The union is checked up front, so a failed merge writes nothing.
+    /// @return Ok(()) if everything was merged, Err(ContainerFull) if the union exceeds capacity
+    pub fn merge<F>(&mut self, mut other: ProbeHashMap<K, V, Size>, resolve: F) -> Result<(), InsertionError>
+    where F: Fn(&V, V) -> V {
+        // Drain the other map's live entries in recency order by walking its linkage
+        let mut incoming = Vec::with_capacity(other.occupied_count);
+        let mut walk_index = other.first_index;
+        while let Some(index) = walk_index {
+            walk_index = other.entry_array[index].linkage.next;
+            let storage = std::mem::replace(&mut other.entry_array[index].storage, Storage::UnOccupied);
+            if let Storage::Occupied(entry) = storage {
+                incoming.push(entry);
+            }
+        }
+
+        // Only genuinely new keys consume slots; collisions resolve in place
+        let additional = incoming.iter()
+            .filter(|entry| { return !self.contains_key(&entry.key); })
+            .count();
+        if !self.can_fit(additional) {
+            return Err(InsertionError::ContainerFull{ occupied: self.occupied_count });
+        }
+
+        for entry in incoming {
+            let value = match self.get(&entry.key) {
+                Some(existing) => resolve(existing, entry.value),
+                None => entry.value,
+            };
+            self.insert(entry.key, value)?;
+        }
+
+        return Ok(());
+    }
+
     /// Removes an entry with key equal to given key
-    pub fn remove<Q>(&mut self, key: &Q)
+    /// @return The removed value if such an entry existed, None otherwise
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
     where K: std::borrow::Borrow<Q>, Q: std::hash::Hash + Eq + ?Sized {
         match self.find_index_of(key) {
-            Some(index) => self.remove_at_index(index),
-            None => {},
+            Some(index) => return self.remove_at_index(index),
+            None => return None,
+        };
+    }
+
+    /// Removes an entry and hands back the owned key along with the value,
+    /// complementing remove for the cases where the key itself matters, such as
+    /// moving the pair into another map. Leaves the usual tombstone behind.
+    /// @return The owned key and value if such an entry existed, None otherwise
+    pub fn take<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where K: std::borrow::Borrow<Q>, Q: std::hash::Hash + Eq + ?Sized {
+        let index = match self.find_index_of(key) {
+            None => return None,
+            Some(index) => index,
         };
+
+        self.unlink(index);
+        let storage = std::mem::replace(&mut self.entry_array[index].storage, Storage::OccupiedDeleted);
+        self.occupied_count -= 1;
+        self.deleted_count += 1;
+        match storage {
+            Storage::Occupied(entry) => return Some((entry.key, entry.value)),
+            _ => {
+                assert!(false, "Undefined behaviour: removal of a non-occupied entry");
+                return None;
+            },
+        }
+    }
+
+    /// Removes every given key in order, reporting per key what was removed.
+    /// @return One element per requested key: Some(value) if it was present and removed, None otherwise
+    pub fn remove_all<'keys, Q: 'keys>(&mut self, keys: impl IntoIterator<Item = &'keys Q>) -> Vec<Option<V>>
+    where K: std::borrow::Borrow<Q>, Q: std::hash::Hash + Eq + ?Sized {
+        let mut removed = Vec::new();
+        for key in keys {
+            removed.push(self.remove(key));
+        }
+        return removed;
     }
     
     /// Returns the value of the entry with key equal to given key.
@@ -303,6 +527,426 @@ impl<K: std::hash::Hash + Eq, V, const Size: usize> ProbeHashMap<K, V, Size> {
         };
     }
     
+    /// Resolves the slot for given key once, for entry-style decided insertion
+    /// @return The occupied or vacant entry view, Err(InsertionError) if the table is full
+    pub fn entry(&mut self, key: K) -> Result<MapEntry<'_, K, V, Size, S>, InsertionError> {
+        match self.find_entry_or_unoccupied_for_key(&key) {
+            FindResult::None => return Err(InsertionError::ContainerFull{ occupied: self.occupied_count }),
+            FindResult::Entry(index) => return Ok(MapEntry::Occupied(OccupiedMapEntry{ map: self, index })),
+            FindResult::UnOccupied(index) => return Ok(MapEntry::Vacant(VacantMapEntry{ map: self, index, key })),
+        };
+    }
+
+    /// Returns a mutable borrow of the value of the entry with key equal to given key.
+    /// @return None if no such entry was found, a mutable borrow of the value otherwise.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where K: std::borrow::Borrow<Q>, Q: std::hash::Hash + Eq + ?Sized {
+        let index = match self.find_index_of(key) {
+            None => return None,
+            Some(index) => index,
+        };
+
+        match &mut self.entry_array[index].storage {
+            &mut Storage::Occupied(ref mut entry) => return Some(&mut entry.value),
+            _ => return None,
+        };
+    }
+
+    /// Swaps the values of the two entries with keys equal to the given keys,
+    /// in place and without touching either entry's recency linking: only the
+    /// values change hands, the keys keep their slots and their order.
+    /// @return true if both keys existed and the values were swapped, false otherwise
+    pub fn swap_values<Q>(&mut self, a: &Q, b: &Q) -> bool
+    where K: std::borrow::Borrow<Q>, Q: std::hash::Hash + Eq + ?Sized {
+        let a_index = match self.find_index_of(a) {
+            None => return false,
+            Some(index) => index,
+        };
+        let b_index = match self.find_index_of(b) {
+            None => return false,
+            Some(index) => index,
+        };
+        if a_index == b_index { // Both keys resolve to the same entry
+            return true;
+        }
+
+        // Split the array so we can borrow both entries mutably at once
+        let (low_index, high_index) = match a_index < b_index {
+            true => (a_index, b_index),
+            false => (b_index, a_index),
+        };
+        let (low_slice, high_slice) = self.entry_array.split_at_mut(high_index);
+        match (&mut low_slice[low_index].storage, &mut high_slice[0].storage) {
+            (&mut Storage::Occupied(ref mut low_entry), &mut Storage::Occupied(ref mut high_entry)) => {
+                std::mem::swap(&mut low_entry.value, &mut high_entry.value);
+                return true;
+            },
+            _ => return false,
+        }
+    }
+
+    /// Renames the entry with key equal to `from` to the key `to`, keeping its
+    /// value and its recency position. Since the new key dictates a different
+    /// bucket, the entry is moved to the slot found by probing for `to` and the
+    /// old slot is tombstoned, with the recency linking rewired to the new index.
+    /// @return true on success; false if `from` is missing, `to` already exists, or no slot is free
+    pub fn rename_key<Q>(&mut self, from: &Q, to: K) -> bool
+    where K: std::borrow::Borrow<Q>, Q: std::hash::Hash + Eq + ?Sized {
+        let from_index = match self.find_index_of(from) {
+            None => return false,
+            Some(index) => index,
+        };
+        // The target key must not exist yet, and we need an unoccupied slot for it
+        let to_index = match self.find_entry_or_unoccupied_for_key(&to) {
+            FindResult::None => return false,
+            FindResult::Entry(_) => return false,
+            FindResult::UnOccupied(index) => index,
+        };
+
+        // Move the value over, leaving a tombstone behind like a removal would
+        let storage = std::mem::replace(&mut self.entry_array[from_index].storage, Storage::OccupiedDeleted);
+        self.deleted_count += 1;
+        let value = match storage {
+            Storage::Occupied(entry) => entry.value,
+            _ => {
+                assert!(false, "Undefined behaviour: find_index_of returned a non-occupied entry");
+                return false;
+            },
+        };
+        self.entry_array[to_index].storage = Storage::Occupied(Entry{ key: to, value });
+
+        // Rewire the recency linking to the new index
+        let previous = self.entry_array[from_index].linkage.previous.take();
+        let next = self.entry_array[from_index].linkage.next.take();
+        match previous {
+            Some(previous_index) => self.entry_array[previous_index].linkage.next = Some(to_index),
+            None => self.first_index = Some(to_index),
+        }
+        match next {
+            Some(next_index) => self.entry_array[next_index].linkage.previous = Some(to_index),
+            None => self.last_index = Some(to_index),
+        }
+        self.entry_array[to_index].linkage.previous = previous;
+        self.entry_array[to_index].linkage.next = next;
+
+        return true;
+    }
+
+    /// Pre-flights a batch of keys against the remaining capacity: counts how
+    /// many of them are genuinely new (deduplicating the batch and keys already
+    /// present) and checks the result against can_fit.
+    /// @return true if inserting the batch would overflow the table
+    pub fn would_overflow(&self, keys: &[K]) -> bool {
+        let mut new_keys: Vec<&K> = Vec::new();
+        for key in keys {
+            if self.contains_key(key) {
+                continue;
+            }
+            if new_keys.iter().any(|seen| { return *seen == key; }) {
+                continue; // A duplicate within the batch costs only one slot
+            }
+            new_keys.push(key);
+        }
+        return !self.can_fit(new_keys.len());
+    }
+
+    /// Inserts the pair, evicting the least recently used entry first if the
+    /// table has no room — the never-failing variant for fixed-capacity caches.
+    /// Insertion here reuses tombstones, so a warm cache keeps paying only the
+    /// probe cost per insert rather than degrading into repeated rehashes.
+    /// @return The evicted key and value, or None when nothing had to make way
+    pub fn entry_or_evict(&mut self, key: K, value: V) -> Option<(K, V)> {
+        match self.find_entry_or_unoccupied_for_key(&key) {
+            FindResult::Entry(index) => {
+                self.update_at_index(index, value);
+                return None;
+            },
+            FindResult::UnOccupied(index) => {
+                self.insert_at_index(index, key, value);
+                return None;
+            },
+            FindResult::None => {},
+        }
+
+        // Make room by evicting the least recently used entry, if any
+        let evicted = match self.first_index {
+            Some(index) => self.evict_at_index(index),
+            None => None, // Nothing but tombstones; the reuse below still finds a slot
+        };
+
+        // The eviction left a tombstone, and the probe visits every slot, so a
+        // reusable slot always exists on the new key's path
+        let index = match self.find_insert_slot_reusing_tombstones(&key) {
+            Some(index) => index,
+            None => {
+                assert!(false, "Undefined behaviour: no reusable slot after an eviction");
+                return evicted;
+            },
+        };
+        if let &Storage::OccupiedDeleted = &self.entry_array[index].storage {
+            self.deleted_count -= 1;
+        }
+        self.insert_at_index(index, key, value);
+        return evicted;
+    }
+
+    /// Finds the first slot on the key's probe path that can take a fresh
+    /// entry, tombstones included. Reusing a tombstone is safe on insertion:
+    /// lookups probe straight through occupied slots either way, so no other
+    /// key's probe sequence is cut short by the overwrite.
+    /// @return The index of the first unoccupied or tombstoned slot, None if every slot holds a live entry
+    fn find_insert_slot_reusing_tombstones(&self, key: &K) -> Option<usize> {
+        let hash = self.hash(key);
+        for step in 0..Size {
+            let index = self.probe_index(hash, step);
+            match &self.entry_array[index].storage {
+                &Storage::UnOccupied | &Storage::OccupiedDeleted => return Some(index),
+                &Storage::Occupied(_) => {},
+            }
+        }
+        return None;
+    }
+
+    /// Removes the entry at given index like a removal would, but hands the
+    /// whole key value pair back to the caller.
+    /// @return The evicted key and value
+    fn evict_at_index(&mut self, index: usize) -> Option<(K, V)> {
+        self.unlink(index);
+        let storage = std::mem::replace(&mut self.entry_array[index].storage, Storage::OccupiedDeleted);
+        self.occupied_count -= 1;
+        self.deleted_count += 1;
+        match storage {
+            Storage::Occupied(entry) => return Some((entry.key, entry.value)),
+            _ => {
+                assert!(false, "Undefined behaviour: eviction of a non-occupied entry");
+                return None;
+            },
+        }
+    }
+
+
+    /// Walks the linking in recency order, letting the predicate mutate each
+    /// value and removing the entries it rejects in the same pass — handy for
+    /// decay-then-drop sweeps over a cache. Surviving entries keep their order.
+    pub fn retain_mut<F: FnMut(&K, &mut V) -> bool>(&mut self, mut predicate: F) {
+        let mut walk_index = self.first_index;
+        while let Some(index) = walk_index {
+            // Capture the next index before a potential removal unlinks this entry
+            walk_index = self.entry_array[index].linkage.next;
+            let keep = match &mut self.entry_array[index].storage {
+                &mut Storage::Occupied(ref mut entry) => predicate(&entry.key, &mut entry.value),
+                _ => {
+                    assert!(false, "Undefined behaviour: the linkage pointed to a non-occupied entry");
+                    true
+                },
+            };
+            if !keep {
+                self.remove_at_index(index);
+            }
+        }
+    }
+
+    /// Yields a diagnostic view of every physical slot in index order, showing
+    /// how the keys are laid out relative to their ideal buckets. Purely for
+    /// teaching and debugging the probing behaviour.
+    /// @return An iterator over each slot index with its BucketState
+    pub fn iter_buckets(&self) -> impl Iterator<Item = (usize, BucketState)> + '_ {
+        return self.entry_array.iter().enumerate().map(move |(index, slot)| {
+            let state = match &slot.storage {
+                &Storage::UnOccupied => BucketState::Empty,
+                &Storage::Occupied(ref entry) => BucketState::Occupied{ ideal_bucket: self.hash(&entry.key) },
+                &Storage::OccupiedDeleted => BucketState::Deleted,
+            };
+            return (index, state);
+        });
+    }
+
+    /// Groups the live keys by the bucket their hash points at, reporting only
+    /// the buckets more than one key is fighting over. The go-to diagnostic for
+    /// tuning Size or judging a hasher against the actual key population.
+    /// @return One group per contested bucket, each holding its colliding keys
+    pub fn collision_groups(&self) -> Vec<Vec<&K>> {
+        let mut buckets: Vec<Vec<&K>> = Vec::with_capacity(Size);
+        buckets.resize_with(Size, || { return Vec::new(); });
+        for entry in &self.entry_array {
+            if let &Storage::Occupied(ref entry) = &entry.storage {
+                buckets[self.hash(&entry.key)].push(&entry.key);
+            }
+        }
+        return buckets.into_iter()
+            .filter(|group| { return group.len() > 1; })
+            .collect();
+    }
+
+    /// Reports the probe steps taken beyond the ideal bucket across all inserts
+    /// and lookups since construction, a cheap yardstick for comparing hashers
+    /// on real key sets. Counted through a relaxed atomic so read-only lookups
+    /// can tally too, and so sharing the map across threads stays possible.
+    /// @return The cumulative number of collision steps
+    pub fn collisions(&self) -> u64 {
+        return self.collision_count.load(std::sync::atomic::Ordering::Relaxed);
+    }
+
+    /// Scans the linking for the entry with the greatest value, a linear
+    /// convenience for use cases like finding the most frequent word. On ties
+    /// the least recently inserted or updated of the tied entries wins.
+    /// @return None if the map is empty, the key and greatest value otherwise
+    pub fn max_by_value(&self) -> Option<(&K, &V)>
+    where V: Ord {
+        return self.extreme_by_value(&|candidate, best| { return candidate > best; });
+    }
+
+    /// Scans the linking for the entry with the smallest value.
+    /// @return None if the map is empty, the key and smallest value otherwise
+    pub fn min_by_value(&self) -> Option<(&K, &V)>
+    where V: Ord {
+        return self.extreme_by_value(&|candidate, best| { return candidate < best; });
+    }
+
+    /// Walks the linking and keeps the entry the given comparison prefers
+    /// @return None if the map is empty, the preferred key and value otherwise
+    fn extreme_by_value(&self, prefer: &dyn Fn(&V, &V) -> bool) -> Option<(&K, &V)> {
+        let mut extreme: Option<(&K, &V)> = None;
+        let mut walk_index = self.first_index;
+        while let Some(index) = walk_index {
+            walk_index = self.entry_array[index].linkage.next;
+            if let &Storage::Occupied(ref entry) = &self.entry_array[index].storage {
+                extreme = match extreme {
+                    None => Some((&entry.key, &entry.value)),
+                    Some((_, best)) if prefer(&entry.value, best) => Some((&entry.key, &entry.value)),
+                    Some(extreme) => Some(extreme),
+                };
+            }
+        }
+        return extreme;
+    }
+
+    /// Builds a map from the given key value pairs, failing cleanly instead of
+    /// silently dropping pairs once the table is full. A repeated key counts as
+    /// an update, exactly as a sequence of insert calls would treat it.
+    /// @return The filled map, or Err(InsertionError) for the first pair that did not fit
+    pub fn from_pairs_checked(pairs: impl IntoIterator<Item = (K, V)>) -> Result<Self, InsertionError>
+    where S: Default {
+        let mut hash_map = Self::with_hasher(S::default());
+        for (key, value) in pairs {
+            hash_map.insert(key, value)?;
+        }
+        return Ok(hash_map);
+    }
+
+    /// Builds a map from an existing vector of pairs, inserting in order so a
+    /// later duplicate key overwrites an earlier one. Unlike a FromIterator
+    /// implementation this is explicitly fallible: distinct keys beyond Size
+    /// surface as ContainerFull instead of being dropped.
+    /// @return The built map, Err(ContainerFull) if the distinct keys exceed Size
+    pub fn from_vec_last_wins(pairs: Vec<(K, V)>) -> Result<Self, InsertionError>
+    where S: Default {
+        let mut hash_map = Self::with_hasher(S::default());
+        for (key, value) in pairs {
+            hash_map.insert(key, value)?;
+        }
+        return Ok(hash_map);
+    }
+
+    /// Returns a copy of the value for given key, or the default value when no
+    /// entry exists, sparing call sites the unwrap_or_default and dereference
+    /// boilerplate for plain numeric values.
+    /// @return The stored value copied out, or V::default()
+    pub fn get_or_default<Q>(&self, key: &Q) -> V
+    where V: Default + Copy, K: std::borrow::Borrow<Q>, Q: std::hash::Hash + Eq + ?Sized {
+        return match self.get(key) {
+            Some(value) => *value,
+            None => V::default(),
+        };
+    }
+
+    /// Returns both the stored key and the value of the entry with key equal to
+    /// given key, useful when the lookup key and the stored key differ in
+    /// representation (e.g. interning).
+    /// @return None if no such entry was found, the stored key and value otherwise.
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where K: std::borrow::Borrow<Q>, Q: std::hash::Hash + Eq + ?Sized {
+        let entry = match self.find_entry(key) {
+            None => return None,
+            Some(entry) => entry,
+        };
+        return Some((&entry.key, &entry.value));
+    }
+
+    /// @return Whether an entry with key equal to given key exists
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where K: std::borrow::Borrow<Q>, Q: std::hash::Hash + Eq + ?Sized {
+        return self.find_index_of(key).is_some();
+    }
+
+    /// Snapshots the current recency order by walking the linking from the
+    /// least recent entry to the most recent one.
+    /// @return The keys from least to most recently inserted or updated
+    pub fn order(&self) -> Vec<&K> {
+        let mut keys = Vec::with_capacity(self.occupied_count);
+        let mut walk_index = self.first_index;
+        while let Some(index) = walk_index {
+            walk_index = self.entry_array[index].linkage.next;
+            match &self.entry_array[index].storage {
+                &Storage::Occupied(ref entry) => keys.push(&entry.key),
+                _ => {
+                    assert!(false, "Undefined behaviour: the linkage pointed to a non-occupied entry");
+                },
+            }
+        }
+        return keys;
+    }
+
+    /// As get_or_insert, but additionally reporting whether the call created
+    /// the entry, so callers tracking unique keys can count accurately.
+    /// @return The value borrow and true when a fresh entry was created, Err(InsertionError) if a needed insertion failed
+    pub fn get_mut_or_insert(&mut self, key: K, default: V) -> Result<(&mut V, bool), InsertionError> {
+        let (index, inserted) = match self.find_entry_or_unoccupied_for_key(&key) {
+            FindResult::None => return Err(InsertionError::ContainerFull{ occupied: self.occupied_count }),
+            FindResult::Entry(index) => (index, false),
+            FindResult::UnOccupied(index) => {
+                self.insert_at_index(index, key, default);
+                (index, true)
+            },
+        };
+
+        match &mut self.entry_array[index].storage {
+            &mut Storage::Occupied(ref mut entry) => return Ok((&mut entry.value, inserted)),
+            _ => unreachable!("The resolved slot holds no live entry"),
+        };
+    }
+
+    /// The eager sibling of get_or_insert_with for defaults that are cheap to
+    /// compute unconditionally. An already-present value is left untouched.
+    /// @return A mutable borrow of the value, Err(InsertionError) if a needed insertion failed
+    pub fn get_or_insert(&mut self, key: K, default: V) -> Result<&mut V, InsertionError> {
+        return self.get_or_insert_with(key, || { return default; });
+    }
+
+    /// Returns a mutable borrow of the value for given key, inserting a value
+    /// built by the given function first if no entry exists yet. As a plain get
+    /// of an existing entry this does not touch the recency linking; only an
+    /// actual insertion makes the entry the new last.
+    /// @return A mutable borrow of the value, Err(InsertionError) if a needed insertion failed
+    pub fn get_or_insert_with(&mut self, key: K, default: impl FnOnce() -> V) -> Result<&mut V, InsertionError> {
+        let index = match self.find_entry_or_unoccupied_for_key(&key) {
+            FindResult::None => return Err(InsertionError::ContainerFull{ occupied: self.occupied_count }),
+            FindResult::Entry(index) => index,
+            FindResult::UnOccupied(index) => {
+                self.insert_at_index(index, key, default());
+                index
+            },
+        };
+
+        match &mut self.entry_array[index].storage {
+            &mut Storage::Occupied(ref mut entry) => return Ok(&mut entry.value),
+            _ => {
+                // find_entry_or_unoccupied and insert_at_index guarantee occupancy
+                unreachable!("get_or_insert_with resolved to a non-occupied entry");
+            },
+        };
+    }
+
     /// @return None if the map is empty, otherwise the last added or updated entry.
     pub fn get_last(&self) -> Option<&Entry<K, V>> {
         let index = match &self.last_index {
@@ -325,6 +969,25 @@ impl<K: std::hash::Hash + Eq, V, const Size: usize> ProbeHashMap<K, V, Size> {
         return Some(entry);
     }
     
+    /// Destructures the least recently added or updated entry into a plain tuple,
+    /// keeping the internal Entry type out of the caller's code
+    /// @return None if the map is empty, Some((&key, &value)) otherwise
+    pub fn first_key_value(&self) -> Option<(&K, &V)> {
+        match self.get_first() {
+            None => return None,
+            Some(entry) => return Some((&entry.key, &entry.value)),
+        };
+    }
+
+    /// Destructures the last added or updated entry into a plain tuple
+    /// @return None if the map is empty, Some((&key, &value)) otherwise
+    pub fn last_key_value(&self) -> Option<(&K, &V)> {
+        match self.get_last() {
+            None => return None,
+            Some(entry) => return Some((&entry.key, &entry.value)),
+        };
+    }
+
     /// @return None if the map is empty, otherwise the most recent 
     pub fn get_first(&self) -> Option<&Entry<K, V>> {
         let index = match &self.first_index {
@@ -346,4 +1009,128 @@ impl<K: std::hash::Hash + Eq, V, const Size: usize> ProbeHashMap<K, V, Size> {
 
         return Some(entry);
     }
-}
\ No newline at end of file
+}
+
+// A multiplicative (Fibonacci) hasher for integer keys. Integer writes fold the
+// value into the state with the golden-ratio constant, spreading sequential IDs
+// across the table far cheaper than RandomState's SipHash. The byte fallback
+// keeps compound keys correct should one end up here, just without the speedup.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MultiplicativeState;
+
+pub struct MultiplicativeHasher {
+    state: u64,
+}
+
+impl std::hash::Hasher for MultiplicativeHasher {
+    fn finish(&self) -> u64 {
+        return self.state;
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.state = (self.state ^ byte as u64).wrapping_mul(0x9E3779B97F4A7C15);
+        }
+    }
+
+    fn write_u8(&mut self, value: u8) {
+        self.write_u64(value as u64);
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        self.write_u64(value as u64);
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.state = (self.state ^ value).wrapping_mul(0x9E3779B97F4A7C15);
+    }
+
+    fn write_usize(&mut self, value: usize) {
+        self.write_u64(value as u64);
+    }
+
+    fn write_i32(&mut self, value: i32) {
+        self.write_u64(value as u64);
+    }
+
+    fn write_i64(&mut self, value: i64) {
+        self.write_u64(value as u64);
+    }
+}
+
+impl std::hash::BuildHasher for MultiplicativeState {
+    type Hasher = MultiplicativeHasher;
+
+    fn build_hasher(&self) -> MultiplicativeHasher {
+        return MultiplicativeHasher{ state: 0 };
+    }
+}
+
+impl<K, V, const Size: usize> ProbeHashMap<K, V, Size, MultiplicativeState> {
+    /// Creates a map on the multiplicative fast path, the hasher of choice for
+    /// plain integer keys where RandomState's DoS resistance is not needed.
+    pub fn with_multiplicative_hasher() -> Self {
+        return Self::with_hasher(MultiplicativeState);
+    }
+}
+
+// A HashMap-style entry API. Named MapEntry as Entry is already taken by our
+// key-value pair struct above. Holding the pre-resolved index means the
+// get-or-insert pattern costs only a single probe sequence.
+pub struct OccupiedMapEntry<'map, K, V, const Size: usize, S: std::hash::BuildHasher = std::hash::RandomState> {
+    map: &'map mut ProbeHashMap<K, V, Size, S>,
+    index: usize,
+}
+
+pub struct VacantMapEntry<'map, K, V, const Size: usize, S: std::hash::BuildHasher = std::hash::RandomState> {
+    map: &'map mut ProbeHashMap<K, V, Size, S>,
+    index: usize,
+    key: K,
+}
+
+pub enum MapEntry<'map, K, V, const Size: usize, S: std::hash::BuildHasher = std::hash::RandomState> {
+    Occupied(OccupiedMapEntry<'map, K, V, Size, S>),
+    Vacant(VacantMapEntry<'map, K, V, Size, S>),
+}
+
+impl<'map, K: std::hash::Hash + Eq, V, const Size: usize, S: std::hash::BuildHasher> OccupiedMapEntry<'map, K, V, Size, S> {
+    /// @return A mutable borrow of the value of the occupied entry
+    pub fn get_mut(&mut self) -> &mut V {
+        match &mut self.map.entry_array[self.index].storage {
+            &mut Storage::Occupied(ref mut entry) => return &mut entry.value,
+            _ => unreachable!("OccupiedMapEntry pointed to a non-occupied slot"),
+        };
+    }
+
+    /// Consumes the entry view in favour of a value borrow with the map's lifetime
+    /// @return A mutable borrow of the value of the occupied entry
+    pub fn into_mut(self) -> &'map mut V {
+        match &mut self.map.entry_array[self.index].storage {
+            &mut Storage::Occupied(ref mut entry) => return &mut entry.value,
+            _ => unreachable!("OccupiedMapEntry pointed to a non-occupied slot"),
+        };
+    }
+}
+
+impl<'map, K: std::hash::Hash + Eq, V, const Size: usize, S: std::hash::BuildHasher> VacantMapEntry<'map, K, V, Size, S> {
+    /// Places the given value into the pre-resolved vacant slot
+    /// @return A mutable borrow of the freshly inserted value
+    pub fn insert(self, value: V) -> &'map mut V {
+        self.map.insert_at_index(self.index, self.key, value);
+        match &mut self.map.entry_array[self.index].storage {
+            &mut Storage::Occupied(ref mut entry) => return &mut entry.value,
+            _ => unreachable!("insert_at_index left a non-occupied slot"),
+        };
+    }
+}
+
+impl<'map, K: std::hash::Hash + Eq, V, const Size: usize, S: std::hash::BuildHasher> MapEntry<'map, K, V, Size, S> {
+    /// Inserts the given value if the entry is vacant
+    /// @return A mutable borrow of the present or freshly inserted value
+    pub fn or_insert(self, default: V) -> &'map mut V {
+        match self {
+            MapEntry::Occupied(occupied) => occupied.into_mut(),
+            MapEntry::Vacant(vacant) => vacant.insert(default),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+// A thin sharing wrapper for the read-only phase of a build-then-read cache.
+// The map goes behind an Arc once construction is done; every clone of the
+// wrapper can then serve lookups from any thread through &self. No interior
+// mutability is involved: ProbeHashMap is Send + Sync whenever K and V are,
+// so plain shared references are all concurrent reads need.
+
+use std::sync::Arc;
+
+use crate::probe_hash_map::ProbeHashMap;
+
+pub struct SharedProbeHashMap<K, V, const Size: usize> {
+    map: Arc<ProbeHashMap<K, V, Size>>,
+}
+
+// A hand-written Clone: deriving it would needlessly require K: Clone, V: Clone
+impl<K, V, const Size: usize> Clone for SharedProbeHashMap<K, V, Size> {
+    fn clone(&self) -> Self {
+        return SharedProbeHashMap { map: Arc::clone(&self.map) };
+    }
+}
+
+impl<K, V, const Size: usize> SharedProbeHashMap<K, V, Size> {
+    /// Takes ownership of a fully built map, freezing it for shared reading
+    pub fn new(map: ProbeHashMap<K, V, Size>) -> Self {
+        return SharedProbeHashMap { map: Arc::new(map) };
+    }
+
+    /// @return The number of live entries currently stored
+    pub fn len(&self) -> usize {
+        return self.map.len();
+    }
+
+    /// @return Whether the map stores no live entries
+    pub fn is_empty(&self) -> bool {
+        return self.map.is_empty();
+    }
+}
+
+impl<K: std::hash::Hash + Eq, V, const Size: usize> SharedProbeHashMap<K, V, Size> {
+    /// Returns the value of the entry with key equal to given key.
+    /// @return None if no such entry was found, the value of the entry otherwise.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where K: std::borrow::Borrow<Q>, Q: std::hash::Hash + Eq + ?Sized {
+        return self.map.get(key);
+    }
+
+    /// @return Whether an entry with key equal to given key exists
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where K: std::borrow::Borrow<Q>, Q: std::hash::Hash + Eq + ?Sized {
+        return self.map.contains_key(key);
+    }
+}
@@ -28,6 +28,44 @@ fn parse_single_100_times(data: &String) {
     }
 }
 
+fn parse_entire_data_raw(data: &String) {
+    let mut parser = Parser::new(data);
+
+    let mut count = 0;
+    loop {
+        match parser.parse_single_raw() {
+            Err(ParseError::EndOfData) => break,
+            Err(error) => {
+                assert!(false, "parse_single_raw produced a non-EndOfData error: {}", error);
+            }
+            Ok(_) => count+=1,
+        }
+    }
+
+    assert_eq!(count, 1436);
+}
+
+fn lex_entire_data(data: &String) {
+    use parser_sample::{Lexer, Token};
+
+    let mut lexer = Lexer::new(data);
+
+    let mut count = 0;
+    loop {
+        match lexer.next_token() {
+            Err(error) => {
+                assert!(false, "next_token produced an error: {}", error);
+            }
+            Ok(None) => break,
+            Ok(Some(Token::NumberValue(_))) => count += 1,
+            Ok(Some(_)) => {},
+        }
+    }
+
+    // Four number fields per entry
+    assert_eq!(count, 1436 * 4);
+}
+
 fn parse_entire_data(data: &String) {
     let mut parser = Parser::new(data);
 
@@ -67,6 +105,16 @@ fn parser_benchmark(criterion: &mut Criterion) {
     criterion.bench_function("parsing entire data", |bencher| {
         bencher.iter(|| parse_entire_data(black_box(&file)));
     });
+
+    // Isolates the lexer, dominated by string and number scanning
+    criterion.bench_function("lexing entire data", |bencher| {
+        bencher.iter(|| lex_entire_data(black_box(&file)));
+    });
+
+    // The borrowing variant skips the per-string allocations of the owned one
+    criterion.bench_function("parsing entire data zero-copy", |bencher| {
+        bencher.iter(|| parse_entire_data_raw(black_box(&file)));
+    });
 }
 
 criterion_group!(benches, parser_benchmark);
@@ -0,0 +1,69 @@
+
+
+// The networking half of the example, factored out of main so it can be
+// exercised against a mock server. Fetching and parsing are deliberately one
+// function: the callers we have never want one without the other.
+
+use crate::parser::{ParseError, Parser, ResultEntry};
+
+// Everything that can go wrong between issuing the GET and the parsed entries
+#[derive(Debug)]
+pub enum FetchError {
+    Http(reqwest::Error), // The request itself or reading its body failed
+    Parse(ParseError), // The body arrived but did not parse as ticker data
+}
+
+// Pretty printing for our FetchError
+impl std::fmt::Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self {
+            &FetchError::Http(ref error) => {
+                write!(f, "The HTTP request failed: {}", error)
+            },
+            &FetchError::Parse(ref error) => {
+                write!(f, "The response body did not parse: {}", error)
+            },
+        }
+    }
+}
+
+impl std::error::Error for FetchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            &FetchError::Http(ref error) => return Some(error),
+            &FetchError::Parse(ref error) => return Some(error),
+        }
+    }
+}
+
+impl From<reqwest::Error> for FetchError {
+    fn from(error: reqwest::Error) -> Self {
+        return FetchError::Http(error);
+    }
+}
+
+impl From<ParseError> for FetchError {
+    fn from(error: ParseError) -> Self {
+        return FetchError::Parse(error);
+    }
+}
+
+/// Issues a blocking GET against the given url, reads the whole body and
+/// parses it as an array of ticker entries.
+/// @return Every parsed entry, or the first error encountered on the way
+pub fn fetch_and_parse(url: &str) -> Result<Vec<ResultEntry>, FetchError> {
+    let client = reqwest::blocking::Client::new();
+    let response = client.get(url).send()?;
+    let body_text = response.text()?;
+
+    let mut parser = Parser::new(&body_text);
+    let mut entries = Vec::new();
+    loop {
+        match parser.parse_single() {
+            Err(ParseError::EndOfData) => break,
+            Err(error) => return Err(FetchError::Parse(error)),
+            Ok(entry) => entries.push(entry),
+        }
+    }
+    return Ok(entries);
+}
@@ -1,2 +1,11 @@
 pub mod parser;
 pub use parser::Parser;
+pub use parser::ParserBuilder;
+pub use parser::{Lexer, Token};
+pub use parser::FromJsonObject;
+pub use parser::{GenericResultEntry, ResultEntry, ResultEntryF32};
+pub use parser::RawEntry;
+pub use parser::JsonValue;
+pub use parser::entries_to_json;
+pub mod fetch;
+pub use fetch::{fetch_and_parse, FetchError};
@@ -14,89 +14,30 @@
 
 use std::time::Instant;
 
-use reqwest::blocking::Client;
-
 use parser_sample::Parser;
 use parser_sample::parser::ParseError;
+use parser_sample::fetch_and_parse;
 
 fn main() {
-    // We're just using a simple library here to access the HTTP request in a blocking way
+    // The networking and parsing live in fetch_and_parse so they can be tested
+    // against a mock server; main just calls it and reports
     let url = "https://eapi.binance.com/eapi/v1/ticker";
 
-    let client = Client::new();
-    let http_result = client.get(url)
-        .send();
-
-    let response = match http_result {
-        Ok(response) => response,
-        Err(error) => {
-            println!("Request was not successful: {:#?}", error);
-            return;
-        },
-    };
-
-    println!("Request was successful: {:#?}", response);
-
-    let body_text = match response.text() {
-        Ok(text) => text,
-        Err(error) => {
-            println!("Request could not be parsed as bytes: {:#?}", error);
-            return;
-        }
-    };
-
-    // Save the result to assets for easy access for testing, benchmarking
-    //let file_path = "./assets/body_text.json";
-    //match std::fs::write(file_path, body_text) {
-    //    Ok(()) => {},
-    //    Err(error) => {
-    //        println!("Writing the response into {} failed: {}", file_path, error);
-    //    }
-    //}
-    //println!("Successfully wrote the response into {}", file_path);
-    //return;
-
-    // Message byte count: 491542
-    println!("Message byte count: {}", body_text.len());
-
-    let mut parser = Parser::new(&body_text);
-
     let start = Instant::now();
-    // Test parsing a single entry.
-    let single_entry = match parser.parse_single() {
+    let entries = match fetch_and_parse(url) {
+        Ok(entries) => entries,
         Err(error) => {
-            println!("Error parsing first entry: {}", error);
+            println!("Fetching the ticker failed: {}", error);
             return;
         },
-        Ok(entry) => entry,
     };
     let duration = start.elapsed();
 
-    // Parsing a single entry took 91.8µs (that's in debug though)
-    println!("Parsing a single entry took {:?}", duration);
-
-    // First  element symbol: ResultEntry { symbol: "BNB-250511-665-P", priceChange: -10.5, 
-    // priceChangePercent: -0.84, lastPrice: 2.0, lastQty: 0.0, open: 12.5, high: 12.5, low: 2.0,
-    // volume: 8.45, amount: 46.58, bidPrice: 1.9, askPrice: 3.0, openTime: 1746897259343, closeTime: 
-    // 1746937541235, firstTradeId: 1, tradeCount: 8, strikePrice: 665.0, exercisePrice: 665.12765896 }
-    // Seems to work fine, on to benchmarking
-    println!("First element symbol: {:?}", single_entry);
-
-    let start = Instant::now();
-    // Parse another 100 entries:
-    for _ in 0..100 {
-        match parser.parse_single() {
-            Err(error) => {
-                println!("Error parsing 100 entries: {}", error);
-                return;
-            },
-            Ok(_) => {},
-        }
+    println!("Fetched and parsed {} entries in {:?}", entries.len(), duration);
+    match entries.first() {
+        Some(entry) => println!("First element: {:?}", entry),
+        None => println!("The endpoint returned an empty array"),
     }
-    let duration = start.elapsed();
-
-    // Parsing 100 further entries took 2.1233ms (that's in debug though)
-    println!("Parsing 100 further entries took {:?}", duration);
 
     // Find benchmarking code in Q2/benches/bench_parser.rs
 }
@@ -144,25 +85,1551 @@ mod tests {
         };
 
         assert!(matches!(second_entry.symbol.as_str(), "ETH-250516-2550-C"));
-        assert!(matches!(second_entry.priceChange, -1.6));
-        assert!(matches!(second_entry.priceChangePercent, -0.0201));
-        assert!(matches!(second_entry.lastPrice, 78.0));
-        assert!(matches!(second_entry.lastQty, 0.2));
+        assert!(matches!(second_entry.price_change, -1.6));
+        assert!(matches!(second_entry.price_change_percent, -0.0201));
+        assert!(matches!(second_entry.last_price, 78.0));
+        assert!(matches!(second_entry.last_qty, 0.2));
         assert!(matches!(second_entry.open, 79.6));
         assert!(matches!(second_entry.high, 115.8)); 
         assert!(matches!(second_entry.low, 77.2)); 
         assert!(matches!(second_entry.volume, 72.26)); 
         assert!(matches!(second_entry.amount, 6090.82)); 
-        assert!(matches!(second_entry.bidPrice, 84.8)); 
-        assert!(matches!(second_entry.askPrice, 85.8)); 
-        assert!(matches!(second_entry.openTime, 1746898120943)); 
-        assert!(matches!(second_entry.closeTime, 1746954696155)); 
-        assert!(matches!(second_entry.firstTradeId, 1));  
-        assert!(matches!(second_entry.tradeCount, 24));  
-        assert!(matches!(second_entry.strikePrice, 2550.0)); 
-        assert!(matches!(second_entry.exercisePrice, 2511.22651163));
+        assert!(matches!(second_entry.bid_price, 84.8)); 
+        assert!(matches!(second_entry.ask_price, 85.8)); 
+        assert!(matches!(second_entry.open_time, 1746898120943)); 
+        assert!(matches!(second_entry.close_time, 1746954696155)); 
+        assert!(matches!(second_entry.first_trade_id, 1));  
+        assert!(matches!(second_entry.trade_count, 24));  
+        assert!(matches!(second_entry.strike_price, 2550.0)); 
+        assert!(matches!(second_entry.exercise_price, 2511.22651163));
     }
     
+    #[test]
+    fn oversized_number_produces_error() {
+        // 25 digits is too large for a u64; this must not panic
+        let data = String::from("[{\"openTime\":1111111111111111111111111}]");
+        let mut parser = Parser::new(&data);
+
+        assert!(matches!(parser.parse_single(), Err(ParseError::ParseIntError{ .. })));
+    }
+
+    #[test]
+    fn escape_sequences_are_decoded() {
+        let data = String::from("[{\"symbol\":\"AB\\\"C\\nD\"}]");
+        let mut parser = Parser::new(&data);
+
+        let entry = match parser.parse_single() {
+            Err(error) => {
+                assert!(false, "parse_single() produced an error: {}", error);
+                return;
+            },
+            Ok(entry) => entry,
+        };
+
+        assert_eq!(entry.symbol, "AB\"C\nD");
+    }
+
+    #[test]
+    fn invalid_escape_produces_error() {
+        let data = String::from("[{\"symbol\":\"AB\\qC\"}]");
+        let mut parser = Parser::new(&data);
+
+        assert!(matches!(parser.parse_single(), Err(ParseError::InvalidEscape('q'))));
+    }
+
+    #[test]
+    fn unicode_escapes_are_decoded() {
+        let data = String::from("[{\"symbol\":\"\\u0041\\u00e9\"}]");
+        let mut parser = Parser::new(&data);
+
+        let entry = match parser.parse_single() {
+            Err(error) => {
+                assert!(false, "parse_single() produced an error: {}", error);
+                return;
+            },
+            Ok(entry) => entry,
+        };
+
+        assert_eq!(entry.symbol, "Aé");
+    }
+
+    #[test]
+    fn surrogate_pairs_are_combined() {
+        // \uD83D\uDE00 encodes the emoji U+1F600
+        let data = String::from("[{\"symbol\":\"\\ud83d\\ude00\"}]");
+        let mut parser = Parser::new(&data);
+
+        let entry = match parser.parse_single() {
+            Err(error) => {
+                assert!(false, "parse_single() produced an error: {}", error);
+                return;
+            },
+            Ok(entry) => entry,
+        };
+
+        assert_eq!(entry.symbol, "\u{1F600}");
+    }
+
+    #[test]
+    fn unpaired_surrogate_produces_error() {
+        let data = String::from("[{\"symbol\":\"\\ud83dxx\"}]");
+        let mut parser = Parser::new(&data);
+
+        assert!(matches!(parser.parse_single(), Err(ParseError::InvalidUnicodeEscape(_))));
+    }
+
+    #[test]
+    fn signed_hex_in_a_unicode_escape_is_rejected() {
+        // from_str_radix would tolerate the '+'; the lexer must not
+        let data = String::from("[{\"symbol\":\"\\u+12f\"}]");
+        let mut parser = Parser::new(&data);
+
+        assert!(matches!(parser.parse_single(), Err(ParseError::InvalidUnicodeEscape(_))));
+    }
+
+    #[test]
+    fn non_ascii_string_values_round_trip() {
+        let data = String::from("[{\"symbol\":\"опцион-🚀-750-C\"}]");
+        let mut parser = Parser::new(&data);
+
+        let entry = match parser.parse_single() {
+            Err(error) => {
+                assert!(false, "parse_single() produced an error: {}", error);
+                return;
+            },
+            Ok(entry) => entry,
+        };
+
+        assert_eq!(entry.symbol, "опцион-🚀-750-C");
+    }
+
+    #[test]
+    fn non_ascii_outside_string_produces_clean_error() {
+        let data = String::from("[é]");
+        let mut parser = Parser::new(&data);
+
+        assert!(matches!(parser.parse_single(), Err(ParseError::UnrecognisedToken{ character: 'é', .. })));
+    }
+
+    #[test]
+    fn boolean_values_lex_cleanly() {
+        // No boolean fields exist yet, so we expect the unrecognised key error
+        // rather than a token-level failure
+        let data = String::from("[{\"flag\":true}]");
+        let mut parser = Parser::new(&data);
+
+        // Errors inside an entry come wrapped with the entry's index
+        match parser.parse_single() {
+            Err(ParseError::AtEntry{ index, error }) => {
+                assert_eq!(index, 1);
+                assert!(matches!(*error, ParseError::UnrecognisedKeyBoolValuePair{ .. }));
+            },
+            other => assert!(false, "Expected a wrapped bool key error, got {:?}", other.map(|entry| { return entry.symbol; })),
+        }
+    }
+
+    #[test]
+    fn null_value_leaves_field_at_default() {
+        let data = String::from("[{\"symbol\":\"X\",\"bidPrice\":null,\"askPrice\":\"3.0\"}]");
+        let mut parser = Parser::new(&data);
+
+        let entry = match parser.parse_single() {
+            Err(error) => {
+                assert!(false, "parse_single() produced an error: {}", error);
+                return;
+            },
+            Ok(entry) => entry,
+        };
+
+        assert!(matches!(entry.bid_price, 0.0));
+        assert!(matches!(entry.ask_price, 3.0));
+    }
+
+    #[test]
+    fn nested_structures_are_skipped() {
+        let data = String::from("[{\"symbol\":\"X\",\"meta\":{\"inner\":[1,2,{\"deep\":true}]},\"lastPrice\":\"7.5\"}]");
+        let mut parser = Parser::new(&data);
+
+        let entry = match parser.parse_single() {
+            Err(error) => {
+                assert!(false, "parse_single() produced an error: {}", error);
+                return;
+            },
+            Ok(entry) => entry,
+        };
+
+        assert!(matches!(entry.symbol.as_str(), "X"));
+        assert!(matches!(entry.last_price, 7.5));
+    }
+
+    #[test]
+    fn iterator_yields_all_entries() {
+        let file_path = "./assets/body_text.json";
+        let file = match std::fs::read_to_string(file_path) {
+            Ok(file) => file,
+            Err(error) => {
+                assert!(false, "Reading the asset file failed: {}", error);
+                return;
+            }
+        };
+
+        let parser = Parser::new(&file);
+
+        let entries = match parser.collect::<Result<Vec<_>, _>>() {
+            Err(error) => {
+                assert!(false, "Iterating the parser produced an error: {}", error);
+                return;
+            },
+            Ok(entries) => entries,
+        };
+
+        assert_eq!(entries.len(), 1436);
+    }
+
+    #[test]
+    fn parse_all_collects_every_entry() {
+        let file_path = "./assets/body_text.json";
+        let file = match std::fs::read_to_string(file_path) {
+            Ok(file) => file,
+            Err(error) => {
+                assert!(false, "Reading the asset file failed: {}", error);
+                return;
+            }
+        };
+
+        let mut parser = Parser::new(&file);
+
+        let entries = match parser.parse_all() {
+            Err(error) => {
+                assert!(false, "parse_all() produced an error: {}", error);
+                return;
+            },
+            Ok(entries) => entries,
+        };
+
+        assert_eq!(entries.len(), 1436);
+    }
+
+    #[test]
+    fn parse_n_resumes_where_it_stopped() {
+        let file_path = "./assets/body_text.json";
+        let file = match std::fs::read_to_string(file_path) {
+            Ok(file) => file,
+            Err(error) => {
+                assert!(false, "Reading the asset file failed: {}", error);
+                return;
+            }
+        };
+
+        // Parse everything in one go as the reference
+        let mut reference_parser = Parser::new(&file);
+        let reference = reference_parser.parse_all().unwrap_or_else(|error| {
+            panic!("parse_all() produced an error: {}", error);
+        });
+
+        let mut parser = Parser::new(&file);
+        let first_batch = parser.parse_n(100).unwrap_or_else(|error| {
+            panic!("parse_n() produced an error: {}", error);
+        });
+        let second_batch = parser.parse_n(100).unwrap_or_else(|error| {
+            panic!("parse_n() produced an error: {}", error);
+        });
+
+        assert_eq!(first_batch.len(), 100);
+        assert_eq!(second_batch.len(), 100);
+        // The second batch continues where the first one stopped
+        assert_eq!(first_batch[99].symbol, reference[99].symbol);
+        assert_eq!(second_batch[0].symbol, reference[100].symbol);
+        assert_eq!(second_batch[99].symbol, reference[199].symbol);
+    }
+
+    #[test]
+    fn parser_accepts_str_slices() {
+        let mut parser = Parser::new("[{\"symbol\":\"X\"}]");
+
+        let entry = match parser.parse_single() {
+            Err(error) => {
+                assert!(false, "parse_single() produced an error: {}", error);
+                return;
+            },
+            Ok(entry) => entry,
+        };
+
+        assert!(matches!(entry.symbol.as_str(), "X"));
+    }
+
+    #[test]
+    fn from_bytes_parses_valid_utf8() {
+        let data = "[{\"symbol\":\"X\"}]".as_bytes();
+        let mut parser = match Parser::from_bytes(data) {
+            Err(error) => {
+                assert!(false, "from_bytes() produced an error: {}", error);
+                return;
+            },
+            Ok(parser) => parser,
+        };
+
+        assert!(parser.parse_single().is_ok());
+    }
+
+    #[test]
+    fn from_bytes_rejects_invalid_utf8() {
+        // A truncated multi-byte sequence: 0xE2 starts a three byte sequence
+        let data: &[u8] = &[b'[', b'{', 0xE2, 0x82];
+
+        assert!(matches!(Parser::from_bytes(data), Err(ParseError::InvalidUtf8(_))));
+    }
+
+    #[test]
+    fn from_reader_parses_the_sample() {
+        let file_path = "./assets/body_text.json";
+        let file = match std::fs::read(file_path) {
+            Ok(file) => file,
+            Err(error) => {
+                assert!(false, "Reading the asset file failed: {}", error);
+                return;
+            }
+        };
+
+        let mut parser = Parser::from_reader(std::io::Cursor::new(file));
+
+        let entries = match parser.parse_all() {
+            Err(error) => {
+                assert!(false, "parse_all() produced an error: {}", error);
+                return;
+            },
+            Ok(entries) => entries,
+        };
+
+        assert_eq!(entries.len(), 1436);
+    }
+
+    #[test]
+    fn parse_error_boxes_as_std_error() {
+        let _boxed: Box<dyn std::error::Error> = ParseError::EndOfData.into();
+    }
+
+    #[test]
+    fn parse_error_exposes_its_source() {
+        use std::error::Error;
+
+        let data = String::from("[{\"lastPrice\":\"notafloat\"}]");
+        let mut parser = Parser::new(&data);
+
+        let error = match parser.parse_single() {
+            Ok(_) => {
+                assert!(false, "parse_single() unexpectedly succeeded");
+                return;
+            },
+            Err(error) => error,
+        };
+
+        // The entry annotation chains to the float parse failure underneath
+        assert!(matches!(error, ParseError::AtEntry{ .. }));
+        match error.source() {
+            Some(source) => assert!(source.to_string().contains("float")),
+            None => assert!(false, "The wrapped error did not expose its source"),
+        }
+    }
+
+    #[test]
+    fn errors_report_their_position() {
+        let data = String::from("[@");
+        let mut parser = Parser::new(&data);
+
+        let error = match parser.parse_single() {
+            Ok(_) => {
+                assert!(false, "parse_single() unexpectedly succeeded");
+                return;
+            },
+            Err(error) => error,
+        };
+
+        match error {
+            ParseError::UnrecognisedToken{ character, position } => {
+                assert_eq!(character, '@');
+                assert_eq!(position.line, 1);
+                assert_eq!(position.column, 2);
+                assert_eq!(position.offset, 1);
+            },
+            other => assert!(false, "Unexpected error: {}", other),
+        }
+    }
+
+    #[test]
+    fn lexer_produces_the_expected_token_sequence() {
+        use parser_sample::{Lexer, Token};
+
+        let mut lexer = Lexer::new("[{\"a\":1}]");
+
+        assert!(matches!(lexer.next_token(), Ok(Some(Token::ArrayStart))));
+        assert!(matches!(lexer.next_token(), Ok(Some(Token::ObjectStart))));
+        match lexer.next_token() {
+            Ok(Some(Token::StringValue(value))) => assert_eq!(value, "a"),
+            _ => assert!(false, "Expected a string token"),
+        }
+        assert!(matches!(lexer.next_token(), Ok(Some(Token::NumberValue(1)))));
+        assert!(matches!(lexer.next_token(), Ok(Some(Token::ObjectEnd))));
+        assert!(matches!(lexer.next_token(), Ok(Some(Token::ArrayEnd))));
+        assert!(matches!(lexer.next_token(), Ok(None)));
+    }
+
+    #[test]
+    fn parse_single_into_fills_a_custom_struct() {
+        use parser_sample::FromJsonObject;
+
+        #[derive(Default)]
+        struct MiniEntry {
+            symbol: String,
+            trade_count: u64,
+        }
+
+        impl FromJsonObject for MiniEntry {
+            fn set_string(&mut self, key: &str, value: String) -> Result<(), ParseError> {
+                match key {
+                    "symbol" => self.symbol = value,
+                    _ => {},
+                }
+                return Ok(());
+            }
+
+            fn set_number(&mut self, key: &str, value: u64) -> Result<(), ParseError> {
+                match key {
+                    "tradeCount" => self.trade_count = value,
+                    _ => {},
+                }
+                return Ok(());
+            }
+        }
+
+        let data = "[{\"symbol\":\"BTC-210129-20000-C\",\"tradeCount\":5}]";
+        let mut parser = Parser::new(data);
+
+        let entry: MiniEntry = match parser.parse_single_into() {
+            Ok(entry) => entry,
+            Err(error) => {
+                assert!(false, "parse_single_into produced an error: {}", error);
+                return;
+            },
+        };
+
+        assert_eq!(entry.symbol, "BTC-210129-20000-C");
+        assert_eq!(entry.trade_count, 5);
+    }
+
+    #[test]
+    fn lenient_mode_ignores_unknown_keys() {
+        let data = "[{\"symbol\":\"BTC-210129-20000-C\",\"newField\":\"x\"}]";
+
+        // Strict mode (the default) reports the unknown key
+        let mut strict_parser = Parser::new(data);
+        match strict_parser.parse_single() {
+            Err(ParseError::AtEntry{ error, .. }) => {
+                match *error {
+                    ParseError::UnrecognisedKeyStringValuePair{ key, .. } => assert_eq!(key, "newField"),
+                    error => assert!(false, "Unexpected wrapped error: {}", error),
+                }
+            },
+            Err(error) => assert!(false, "Unexpected error: {}", error),
+            Ok(_) => assert!(false, "Strict mode accepted an unknown key"),
+        }
+
+        // Lenient mode skips it and still produces the entry
+        let mut lenient_parser = Parser::new(data);
+        lenient_parser.set_lenient(true);
+        match lenient_parser.parse_single() {
+            Ok(entry) => assert_eq!(entry.symbol, "BTC-210129-20000-C"),
+            Err(error) => assert!(false, "Lenient mode produced an error: {}", error),
+        }
+    }
+
+    #[test]
+    fn missing_required_fields_are_reported() {
+        // An object missing symbol and last_price, but providing everything else
+        let data = "[{\"priceChange\":\"1\",\"priceChangePercent\":\"1\",\"lastQty\":\"1\",\"open\":\"1\",\"high\":\"1\",\"low\":\"1\",\"volume\":\"1\",\"amount\":\"1\",\"bidPrice\":\"1\",\"askPrice\":\"1\",\"openTime\":1,\"closeTime\":1,\"firstTradeId\":1,\"tradeCount\":1,\"strikePrice\":\"1\",\"exercisePrice\":\"1\"}]";
+
+        let mut parser = Parser::new(data);
+        parser.set_check_missing_fields(true);
+
+        match parser.parse_single() {
+            Err(ParseError::AtEntry{ error, .. }) => {
+                match *error {
+                    ParseError::MissingFields{ missing } => {
+                        assert_eq!(missing, vec![String::from("symbol"), String::from("lastPrice")]);
+                    },
+                    error => assert!(false, "Unexpected wrapped error: {}", error),
+                }
+            },
+            Err(error) => assert!(false, "Unexpected error: {}", error),
+            Ok(_) => assert!(false, "The incomplete object was accepted"),
+        }
+
+        // Without the check the same object parses into defaults
+        let mut unchecked_parser = Parser::new(data);
+        match unchecked_parser.parse_single() {
+            Ok(entry) => assert_eq!(entry.symbol, ""),
+            Err(error) => assert!(false, "Unchecked parse produced an error: {}", error),
+        }
+    }
+
+    #[test]
+    fn duplicate_keys_are_reported() {
+        let data = "[{\"open\":\"1.0\",\"open\":\"2.0\"}]";
+
+        let mut parser = Parser::new(data);
+        parser.set_check_duplicate_keys(true);
+
+        // The duplicate is reported with the entry it appeared in, like any
+        // other entry-level error
+        match parser.parse_single() {
+            Err(ParseError::AtEntry{ index, error }) => {
+                assert_eq!(index, 1);
+                match *error {
+                    ParseError::DuplicateKey{ ref key } => assert_eq!(key, "open"),
+                    ref other => assert!(false, "Expected a duplicate key, got {:?}", other),
+                }
+            },
+            Err(error) => assert!(false, "Unexpected error: {}", error),
+            Ok(_) => assert!(false, "The duplicate key was accepted"),
+        }
+
+        // Without the check the later value silently wins
+        let mut unchecked_parser = Parser::new(data);
+        match unchecked_parser.parse_single() {
+            Ok(entry) => assert_eq!(entry.open, 2.0),
+            Err(error) => assert!(false, "Unchecked parse produced an error: {}", error),
+        }
+    }
+
+    #[test]
+    fn f32_entries_parse_with_reduced_precision() {
+        use parser_sample::ResultEntryF32;
+
+        let data = "[{\"symbol\":\"BTC-210129-20000-C\",\"lastPrice\":\"2010.5\",\"volume\":\"8.45\"}]";
+
+        let mut parser = Parser::new(data);
+        let entry: ResultEntryF32 = match parser.parse_single_into() {
+            Ok(entry) => entry,
+            Err(error) => {
+                assert!(false, "parse_single_into produced an error: {}", error);
+                return;
+            },
+        };
+
+        // The f32 values approximate their f64 counterparts
+        assert!((entry.last_price as f64 - 2010.5).abs() < 1e-3);
+        assert!((entry.volume as f64 - 8.45).abs() < 1e-6);
+    }
+
+    #[test]
+    fn thirteen_digit_timestamps_survive_as_u64() {
+        // Millisecond epoch timestamps exceed 32 bits; with u64 storage they are
+        // exact regardless of the target's pointer width
+        let data = "[{\"openTime\":1746937541235,\"closeTime\":1746937541236}]";
+        let mut parser = Parser::new(data);
+
+        let entry = match parser.parse_single() {
+            Err(error) => {
+                assert!(false, "parse_single() produced an error: {}", error);
+                return;
+            },
+            Ok(entry) => entry,
+        };
+
+        assert_eq!(entry.open_time, 1746937541235u64);
+        assert_eq!(entry.close_time, 1746937541236u64);
+    }
+
+    #[test]
+    fn raw_entries_borrow_their_symbol() {
+        use std::borrow::Cow;
+
+        let data = "[{\"symbol\":\"BTC-210129-20000-C\",\"lastPrice\":\"2010.5\"},{\"symbol\":\"A\\nB\"}]";
+        let mut parser = Parser::new(data);
+
+        let entry = match parser.parse_single_raw() {
+            Err(error) => {
+                assert!(false, "parse_single_raw() produced an error: {}", error);
+                return;
+            },
+            Ok(entry) => entry,
+        };
+
+        // An escape-free symbol is borrowed straight from the input
+        assert!(matches!(entry.symbol, Cow::Borrowed(_)));
+        assert_eq!(entry.symbol, "BTC-210129-20000-C");
+        assert_eq!(entry.last_price, 2010.5);
+
+        // A symbol containing an escape falls back to an owned copy
+        let second_entry = match parser.parse_single_raw() {
+            Err(error) => {
+                assert!(false, "parse_single_raw() produced an error: {}", error);
+                return;
+            },
+            Ok(entry) => entry,
+        };
+        assert!(matches!(second_entry.symbol, Cow::Owned(_)));
+        assert_eq!(second_entry.symbol, "A\nB");
+    }
+
+    #[test]
+    fn reset_allows_reusing_a_parser() {
+        let file_path = "./assets/body_text.json";
+        let file = match std::fs::read_to_string(file_path) {
+            Ok(file) => file,
+            Err(error) => {
+                assert!(false, "Reading the asset file failed: {}", error);
+                return;
+            }
+        };
+
+        let mut parser = Parser::new(&file);
+
+        let mut count = 0;
+        loop {
+            match parser.parse_single() {
+                Err(ParseError::EndOfData) => break,
+                Err(error) => assert!(false, "parse_single produced a non-EndOfData error: {}", error),
+                Ok(_) => count += 1,
+            }
+        }
+        assert_eq!(count, 1436);
+
+        // After a reset the same parser runs the full data again
+        parser.reset(&file);
+        let mut second_count = 0;
+        loop {
+            match parser.parse_single() {
+                Err(ParseError::EndOfData) => break,
+                Err(error) => assert!(false, "parse_single produced a non-EndOfData error: {}", error),
+                Ok(_) => second_count += 1,
+            }
+        }
+        assert_eq!(second_count, 1436);
+    }
+
+    #[test]
+    fn feeding_data_in_halves_parses_everything() {
+        let file_path = "./assets/body_text.json";
+        let file = match std::fs::read_to_string(file_path) {
+            Ok(file) => file,
+            Err(error) => {
+                assert!(false, "Reading the asset file failed: {}", error);
+                return;
+            }
+        };
+
+        // Split roughly in the middle of the body, which lands mid-object
+        let middle = file.len() / 2;
+        let (first_half, second_half) = file.split_at(middle);
+
+        let mut parser = Parser::new_fed();
+        parser.feed(first_half);
+
+        let mut count = 0;
+        loop {
+            match parser.parse_single() {
+                Err(ParseError::NeedMoreData) => break,
+                Err(error) => assert!(false, "parse_single produced an unexpected error: {}", error),
+                Ok(_) => count += 1,
+            }
+        }
+        assert!(count > 0, "The first half should contain complete entries");
+
+        parser.feed(second_half);
+        loop {
+            match parser.parse_single() {
+                Err(ParseError::EndOfData) => break,
+                Err(error) => assert!(false, "parse_single produced an unexpected error: {}", error),
+                Ok(_) => count += 1,
+            }
+        }
+
+        assert_eq!(count, 1436);
+    }
+
+    #[test]
+    fn rewinding_a_fed_parser_keeps_entry_indices_stable() {
+        let mut parser = Parser::new_fed();
+
+        // The second entry is cut off mid-object, forcing a rewind; retrying
+        // without new data rewinds a second time
+        parser.feed("[{\"symbol\":\"A\"},{\"sym");
+        assert!(matches!(parser.parse_single(), Ok(_)));
+        assert!(matches!(parser.parse_single(), Err(ParseError::NeedMoreData)));
+        assert!(matches!(parser.parse_single(), Err(ParseError::NeedMoreData)));
+
+        // Completing the entry with an unrecognised key reports it at entry
+        // index 2: the rewinds must not have inflated the counter
+        parser.feed("bol\":\"B\",\"vol\":\"1\"}]");
+        match parser.parse_single() {
+            Err(ParseError::AtEntry{ index, error }) => {
+                assert_eq!(index, 2);
+                assert!(matches!(*error, ParseError::UnrecognisedKeyStringValuePair{ .. }));
+            },
+            other => assert!(false, "Expected an error at entry 2, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn display_renders_a_compact_summary() {
+        let data = "[{\"symbol\":\"BNB-250511-665-P\",\"lastPrice\":\"2.0\",\"volume\":\"8.45\"}]";
+        let mut parser = Parser::new(data);
+
+        let entry = match parser.parse_single() {
+            Err(error) => {
+                assert!(false, "parse_single() produced an error: {}", error);
+                return;
+            },
+            Ok(entry) => entry,
+        };
+
+        assert_eq!(format!("{}", entry), "BNB-250511-665-P last=2 vol=8.45");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_and_custom_parser_agree_on_the_sample() {
+        use parser_sample::ResultEntry;
+
+        let file_path = "./assets/body_text.json";
+        let file = match std::fs::read_to_string(file_path) {
+            Ok(file) => file,
+            Err(error) => {
+                assert!(false, "Reading the asset file failed: {}", error);
+                return;
+            }
+        };
+
+        let serde_entries: Vec<ResultEntry> = match serde_json::from_str(&file) {
+            Ok(entries) => entries,
+            Err(error) => {
+                assert!(false, "serde_json failed to read the sample: {}", error);
+                return;
+            },
+        };
+
+        let mut parser = Parser::new(&file);
+        let mut index = 0;
+        loop {
+            let entry = match parser.parse_single() {
+                Err(ParseError::EndOfData) => break,
+                Err(error) => {
+                    assert!(false, "parse_single produced a non-EndOfData error: {}", error);
+                    return;
+                },
+                Ok(entry) => entry,
+            };
+
+            let reference = &serde_entries[index];
+            assert_eq!(entry.symbol, reference.symbol);
+            assert_eq!(entry.last_price, reference.last_price);
+            assert_eq!(entry.volume, reference.volume);
+            assert_eq!(entry.open_time, reference.open_time);
+            assert_eq!(entry.trade_count, reference.trade_count);
+            index += 1;
+        }
+
+        assert_eq!(index, serde_entries.len());
+    }
+
+    #[test]
+    fn symbol_filter_skips_unwanted_entries() {
+        let file_path = "./assets/body_text.json";
+        let file = match std::fs::read_to_string(file_path) {
+            Ok(file) => file,
+            Err(error) => {
+                assert!(false, "Reading the asset file failed: {}", error);
+                return;
+            }
+        };
+
+        let mut parser = Parser::new(&file);
+        parser.set_symbol_filter(|symbol| { return symbol.starts_with("ETH"); });
+
+        let mut count = 0;
+        loop {
+            match parser.parse_single() {
+                Err(ParseError::EndOfData) => break,
+                Err(error) => assert!(false, "parse_single produced a non-EndOfData error: {}", error),
+                Ok(entry) => {
+                    assert!(entry.symbol.starts_with("ETH"), "Filter let {} through", entry.symbol);
+                    count += 1;
+                },
+            }
+        }
+
+        assert!(count > 0, "The sample contains ETH entries");
+        assert!(count < 1436, "The filter should reject some entries");
+    }
+
+    #[test]
+    fn count_entries_matches_the_sample_size() {
+        let file_path = "./assets/body_text.json";
+        let file = match std::fs::read_to_string(file_path) {
+            Ok(file) => file,
+            Err(error) => {
+                assert!(false, "Reading the asset file failed: {}", error);
+                return;
+            }
+        };
+
+        let mut parser = Parser::new(&file);
+        match parser.count_entries() {
+            Ok(count) => assert_eq!(count, 1436),
+            Err(error) => assert!(false, "count_entries produced an error: {}", error),
+        }
+    }
+
+    #[test]
+    fn count_entries_agrees_with_parse_all_on_nested_shapes() {
+        // Nested arrays are flattened by the parser, so every object directly
+        // inside the array nesting is an entry
+        let data = "[[{\"symbol\":\"A\"},{\"symbol\":\"B\"}],[{\"symbol\":\"C\"}]]";
+        let parsed = match Parser::new(data).parse_all() {
+            Ok(entries) => entries.len(),
+            Err(error) => {
+                assert!(false, "parse_all produced an error: {}", error);
+                return;
+            },
+        };
+        match Parser::new(data).count_entries() {
+            Ok(count) => assert_eq!(count, parsed),
+            Err(error) => assert!(false, "count_entries produced an error: {}", error),
+        }
+
+        // A bare object document is one entry; its nested values belong to it
+        let data = "{\"symbol\":\"A\",\"greeks\":{\"delta\":\"0.5\"}}";
+        let parsed = match Parser::new(data).parse_all() {
+            Ok(entries) => entries.len(),
+            Err(error) => {
+                assert!(false, "parse_all produced an error: {}", error);
+                return;
+            },
+        };
+        assert_eq!(parsed, 1);
+        match Parser::new(data).count_entries() {
+            Ok(count) => assert_eq!(count, parsed),
+            Err(error) => assert!(false, "count_entries produced an error: {}", error),
+        }
+    }
+
+    #[test]
+    fn counting_a_stray_closing_bracket_is_an_error_not_a_panic() {
+        let data = String::from("]");
+        let mut parser = Parser::new(&data);
+        assert!(matches!(parser.count_entries(), Err(ParseError::UnexpectedToken{ .. })));
+
+        let data = String::from("}");
+        let mut parser = Parser::new(&data);
+        assert!(matches!(parser.count_entries(), Err(ParseError::UnexpectedToken{ .. })));
+    }
+
+    #[test]
+    fn entry_limit_stops_further_parsing() {
+        let file_path = "./assets/body_text.json";
+        let file = match std::fs::read_to_string(file_path) {
+            Ok(file) => file,
+            Err(error) => {
+                assert!(false, "Reading the asset file failed: {}", error);
+                return;
+            }
+        };
+
+        let mut parser = Parser::new(&file);
+        parser.set_max_entries(10);
+
+        for index in 0..10 {
+            match parser.parse_single() {
+                Err(error) => assert!(false, "parse_single call {} produced an error: {}", index, error),
+                Ok(_) => {},
+            }
+        }
+
+        assert!(matches!(parser.parse_single(), Err(ParseError::EntryLimitReached)));
+    }
+
+    #[test]
+    fn unknown_keys_can_be_captured() {
+        let data = "[{\"symbol\":\"BTC-210129-20000-C\",\"foo\":\"bar\",\"baz\":7}]";
+
+        let mut parser = Parser::new(data);
+        parser.set_capture_unknown_keys(true);
+
+        let entry = match parser.parse_single() {
+            Err(error) => {
+                assert!(false, "parse_single() produced an error: {}", error);
+                return;
+            },
+            Ok(entry) => entry,
+        };
+
+        assert_eq!(entry.symbol, "BTC-210129-20000-C");
+        assert_eq!(entry.extra["foo"], "bar");
+        assert_eq!(entry.extra["baz"], "7");
+    }
+
+    #[test]
+    fn bytes_consumed_tracks_entry_boundaries() {
+        let data = "[{\"symbol\":\"BTC\"},{\"symbol\":\"ETH\"}]";
+        let mut parser = Parser::new(data);
+
+        match parser.parse_single() {
+            Err(error) => assert!(false, "parse_single() produced an error: {}", error),
+            Ok(_) => {},
+        }
+
+        // After the first entry the parser stands right behind its closing brace,
+        // which is directly before the second object's start
+        let consumed = parser.bytes_consumed();
+        let first_close = data.find('}').unwrap();
+        assert_eq!(consumed, first_close + 1);
+        assert_eq!(data[consumed..].find('{').unwrap(), 1);
+
+        // Multi-byte characters count with their full UTF-8 length
+        let unicode_data = "[{\"symbol\":\"é\"}]";
+        let mut unicode_parser = Parser::new(unicode_data);
+        match unicode_parser.parse_single() {
+            Err(error) => assert!(false, "parse_single() produced an error: {}", error),
+            Ok(_) => {},
+        }
+        assert_eq!(unicode_parser.bytes_consumed(), unicode_data.find('}').unwrap() + 1);
+    }
+
+    #[test]
+    fn empty_symbol_is_rejected_when_validating() {
+        let data = "[{\"symbol\":\"\",\"lastPrice\":\"1.0\"}]";
+
+        let mut parser = Parser::new(data);
+        parser.set_validate_symbol(true);
+        match parser.parse_single() {
+            Err(ParseError::AtEntry{ index, error }) => {
+                assert_eq!(index, 1);
+                assert!(matches!(*error, ParseError::EmptySymbol));
+            },
+            Err(error) => assert!(false, "Unexpected error: {}", error),
+            Ok(_) => assert!(false, "The empty symbol was accepted"),
+        }
+
+        // Without validation the empty symbol passes through
+        let mut unchecked_parser = Parser::new(data);
+        match unchecked_parser.parse_single() {
+            Ok(entry) => assert_eq!(entry.symbol, ""),
+            Err(error) => assert!(false, "Unchecked parse produced an error: {}", error),
+        }
+    }
+
+    #[test]
+    fn remaining_input_shows_the_unconsumed_tail() {
+        let data = String::from("[{\"symbol\":\"A\"},{\"symbol\":\"B\"}]");
+        let mut parser = Parser::new(&data);
+
+        match parser.parse_single() {
+            Ok(entry) => assert_eq!(entry.symbol, "A"),
+            Err(error) => assert!(false, "parse_single produced an error: {}", error),
+        }
+
+        // The first object has been consumed; the separating comma has not
+        assert_eq!(parser.remaining_input(), Some(",{\"symbol\":\"B\"}]"));
+    }
+
+    #[test]
+    fn a_bare_object_parses_as_a_single_entry() {
+        let data = String::from("{\"symbol\":\"BTC-250511-100-C\",\"volume\":\"8.45\"}");
+        let mut parser = Parser::new(&data);
+
+        match parser.parse_single() {
+            Ok(entry) => {
+                assert_eq!(entry.symbol, "BTC-250511-100-C");
+                assert_eq!(entry.volume, 8.45);
+            },
+            Err(error) => assert!(false, "parse_single produced an error: {}", error),
+        }
+
+        assert!(matches!(parser.parse_single(), Err(ParseError::EndOfData)));
+    }
+
+    #[test]
+    fn a_misplaced_token_is_a_clean_error() {
+        // The closing bracket where a value is expected must not be swallowed
+        let data = String::from("[{\"symbol\":]}]");
+        let mut parser = Parser::new(&data);
+
+        assert!(matches!(parser.parse_single(), Err(ParseError::UnexpectedToken{ .. })));
+    }
+
+    #[test]
+    fn the_builder_configures_a_parser() {
+        use parser_sample::ParserBuilder;
+
+        let data = String::from("[{\"symbol\":\"A\",\"mystery\":\"1\"},{\"symbol\":\"B\"},{\"symbol\":\"C\"}]");
+        let mut parser = ParserBuilder::new()
+            .lenient(true)
+            .max_entries(2)
+            .build(&data);
+
+        // Leniency lets the unknown key through; the limit stops the third entry
+        match parser.parse_single() {
+            Ok(entry) => assert_eq!(entry.symbol, "A"),
+            Err(error) => assert!(false, "parse_single produced an error: {}", error),
+        }
+        match parser.parse_single() {
+            Ok(entry) => assert_eq!(entry.symbol, "B"),
+            Err(error) => assert!(false, "parse_single produced an error: {}", error),
+        }
+        assert!(matches!(parser.parse_single(), Err(ParseError::EntryLimitReached)));
+    }
+
+    #[test]
+    fn quoted_integer_fields_are_accepted() {
+        let data = String::from("[{\"symbol\":\"A\",\"tradeCount\":\"24\",\"openTime\":\"1592317127349\"}]");
+        let mut parser = Parser::new(&data);
+
+        match parser.parse_single() {
+            Ok(entry) => {
+                assert_eq!(entry.trade_count, 24);
+                assert_eq!(entry.open_time, 1592317127349);
+            },
+            Err(error) => assert!(false, "parse_single produced an error: {}", error),
+        }
+    }
+
+    #[test]
+    fn peeking_does_not_consume_the_entry() {
+        let data = String::from("[{\"symbol\":\"A\"},{\"symbol\":\"B\"}]");
+        let mut parser = Parser::new(&data);
+
+        match parser.peek_entry() {
+            Ok(entry) => assert_eq!(entry.symbol, "A"),
+            Err(error) => assert!(false, "peek_entry produced an error: {}", error),
+        }
+
+        // The peeked entry is still the next one parse_single returns
+        match parser.parse_single() {
+            Ok(entry) => assert_eq!(entry.symbol, "A"),
+            Err(error) => assert!(false, "parse_single produced an error: {}", error),
+        }
+        match parser.parse_single() {
+            Ok(entry) => assert_eq!(entry.symbol, "B"),
+            Err(error) => assert!(false, "parse_single produced an error: {}", error),
+        }
+    }
+
+    #[test]
+    fn a_peeked_entry_is_honoured_by_the_other_consumers() {
+        let data = "[{\"symbol\":\"A\"},{\"symbol\":\"B\"},{\"symbol\":\"C\"}]";
+
+        // Skipping after a peek discards the peeked entry, not the one after it
+        let mut parser = Parser::new(data);
+        match parser.peek_entry() {
+            Ok(entry) => assert_eq!(entry.symbol, "A"),
+            Err(error) => assert!(false, "peek_entry produced an error: {}", error),
+        }
+        assert!(matches!(parser.skip_entry(), Ok(true)));
+        match parser.parse_single() {
+            Ok(entry) => assert_eq!(entry.symbol, "B"),
+            Err(error) => assert!(false, "parse_single produced an error: {}", error),
+        }
+
+        // Counting includes the buffered entry, and the typed and raw parsers
+        // refuse to run past it rather than reordering the stream
+        let mut parser = Parser::new(data);
+        assert!(parser.peek_entry().is_ok());
+        assert!(matches!(parser.parse_single_raw(), Err(ParseError::PendingPeek)));
+        match parser.count_entries() {
+            Ok(count) => assert_eq!(count, 3),
+            Err(error) => assert!(false, "count_entries produced an error: {}", error),
+        }
+    }
+
+    #[test]
+    fn empty_objects_and_trailing_commas_are_tolerated() {
+        // An empty object yields a default entry
+        let data = String::from("[{}]");
+        let mut parser = Parser::new(&data);
+        match parser.parse_single() {
+            Ok(entry) => {
+                assert_eq!(entry.symbol, "");
+                assert_eq!(entry.volume, 0.0);
+            },
+            Err(error) => assert!(false, "parse_single produced an error: {}", error),
+        }
+        assert!(matches!(parser.parse_single(), Err(ParseError::EndOfData)));
+
+        // A trailing comma before the closing bracket is harmless
+        let data = String::from("[{\"symbol\":\"X\"},]");
+        let mut parser = Parser::new(&data);
+        match parser.parse_single() {
+            Ok(entry) => assert_eq!(entry.symbol, "X"),
+            Err(error) => assert!(false, "parse_single produced an error: {}", error),
+        }
+        assert!(matches!(parser.parse_single(), Err(ParseError::EndOfData)));
+    }
+
+    #[test]
+    fn an_empty_array_is_a_clean_end_of_data() {
+        // A degenerate off-hours response: no entries at all
+        let data = String::from("[]");
+        let mut parser = Parser::new(&data);
+
+        assert!(matches!(parser.parse_single(), Err(ParseError::EndOfData)));
+        // And it stays that way on repeated calls
+        assert!(matches!(parser.parse_single(), Err(ParseError::EndOfData)));
+    }
+
+    #[test]
+    fn nested_arrays_of_objects_are_flattened() {
+        let data = String::from("[[{\"symbol\":\"A\"},{\"symbol\":\"B\"}],[{\"symbol\":\"C\"}]]");
+        let mut parser = Parser::new(&data);
+
+        let mut symbols = Vec::new();
+        loop {
+            match parser.parse_single() {
+                Err(ParseError::EndOfData) => break,
+                Err(error) => {
+                    assert!(false, "parse_single produced an error: {}", error);
+                    return;
+                },
+                Ok(entry) => symbols.push(entry.symbol),
+            }
+        }
+        assert_eq!(symbols, vec!["A", "B", "C"]);
+    }
+
+    #[test]
+    fn for_each_entry_streams_without_collecting() {
+        let data = String::from("[{\"symbol\":\"A\",\"volume\":\"1.5\"},{\"symbol\":\"B\",\"volume\":\"2.25\"}]");
+        let mut parser = Parser::new(&data);
+
+        let mut total_volume = 0.0;
+        match parser.for_each_entry(|entry| { total_volume += entry.volume; }) {
+            Ok(()) => {},
+            Err(error) => assert!(false, "for_each_entry produced an error: {}", error),
+        }
+        assert_eq!(total_volume, 3.75);
+    }
+
+    #[test]
+    fn a_char_iterator_works_as_a_source() {
+        let data = String::from("[{\"symbol\":\"X\"}]");
+        let mut parser = Parser::from_chars(data.chars());
+
+        match parser.parse_single() {
+            Ok(entry) => assert_eq!(entry.symbol, "X"),
+            Err(error) => assert!(false, "parse_single produced an error: {}", error),
+        }
+        assert!(matches!(parser.parse_single(), Err(ParseError::EndOfData)));
+    }
+
+    #[test]
+    fn errors_report_the_entry_index() {
+        // The fifth object carries a malformed float
+        let mut objects = Vec::new();
+        for index in 0..6 {
+            match index {
+                4 => objects.push(String::from("{\"symbol\":\"E\",\"lastPrice\":\"oops\"}")),
+                _ => objects.push(format!("{{\"symbol\":\"S{}\"}}", index)),
+            }
+        }
+        let data = format!("[{}]", objects.join(","));
+        let mut parser = Parser::new(&data);
+
+        match parser.parse_all() {
+            Err(ParseError::AtEntry{ index, error }) => {
+                assert_eq!(index, 5);
+                assert!(matches!(*error, ParseError::ParseFloatError{ .. }));
+            },
+            Err(error) => assert!(false, "Unexpected error: {}", error),
+            Ok(_) => assert!(false, "The malformed float was accepted"),
+        }
+    }
+
+    #[test]
+    fn scientific_notation_floats_parse() {
+        // The float path hands the full string to parse(), which understands
+        // exponent notation — make sure nothing upstream rejects the 'e'
+        let data = String::from("[{\"symbol\":\"X\",\"strikePrice\":\"1.5e3\",\"exercisePrice\":\"2.511e3\"}]");
+        let mut parser = Parser::new(&data);
+
+        match parser.parse_single() {
+            Ok(entry) => {
+                assert_eq!(entry.strike_price, 1500.0);
+                assert_eq!(entry.exercise_price, 2511.0);
+            },
+            Err(error) => assert!(false, "parse_single produced an error: {}", error),
+        }
+    }
+
+    #[test]
+    fn a_truncated_string_is_flagged() {
+        let data = String::from("[{\"symbol\":\"ET");
+        let mut parser = Parser::new(&data);
+
+        match parser.parse_single() {
+            Err(ParseError::UnterminatedString(partial)) => assert_eq!(partial, "ET"),
+            Err(error) => assert!(false, "Unexpected error: {}", error),
+            Ok(_) => assert!(false, "The truncated string was accepted"),
+        }
+    }
+
+    #[test]
+    fn key_order_is_recorded_on_request() {
+        let data = String::from("[{\"volume\":\"1\",\"symbol\":\"X\",\"open\":\"2\"}]");
+        let mut parser = Parser::new(&data);
+        parser.set_record_key_order(true);
+
+        match parser.parse_single() {
+            Ok(entry) => {
+                assert_eq!(entry.key_order(), &[String::from("volume"), String::from("symbol"), String::from("open")]);
+            },
+            Err(error) => assert!(false, "parse_single produced an error: {}", error),
+        }
+
+        // Without the flag nothing is recorded
+        let mut parser = Parser::new(&data);
+        match parser.parse_single() {
+            Ok(entry) => assert!(entry.key_order().is_empty()),
+            Err(error) => assert!(false, "parse_single produced an error: {}", error),
+        }
+    }
+
+    #[test]
+    fn unquoted_floats_and_exponents_lex() {
+        use parser_sample::{Lexer, Token};
+
+        let data = String::from("[1e+2,-1.5,2.5e-1]");
+        let mut lexer = Lexer::new(&data);
+
+        assert!(matches!(lexer.next_token(), Ok(Some(Token::ArrayStart))));
+        match lexer.next_token() {
+            Ok(Some(Token::FloatValue(value))) => assert_eq!(value, 100.0),
+            other => assert!(false, "Expected a float token, got {:?}", other),
+        }
+        match lexer.next_token() {
+            Ok(Some(Token::FloatValue(value))) => assert_eq!(value, -1.5),
+            other => assert!(false, "Expected a float token, got {:?}", other),
+        }
+        match lexer.next_token() {
+            Ok(Some(Token::FloatValue(value))) => assert_eq!(value, 0.25),
+            other => assert!(false, "Expected a float token, got {:?}", other),
+        }
+
+        // A stray plus is not a number start
+        let data = String::from("[+1]");
+        let mut lexer = Lexer::new(&data);
+        assert!(matches!(lexer.next_token(), Ok(Some(Token::ArrayStart))));
+        match lexer.next_token() {
+            Err(ParseError::UnrecognisedToken{ character, .. }) => assert_eq!(character, '+'),
+            other => assert!(false, "Expected an unrecognised token error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn degenerate_numbers_lex_correctly() {
+        use parser_sample::{Lexer, Token};
+
+        // A bare zero, sloppy leading zeros, and signed zero floats
+        let data = String::from("[0,007,0.0,-0.0]");
+        let mut lexer = Lexer::new(&data);
+
+        assert!(matches!(lexer.next_token(), Ok(Some(Token::ArrayStart))));
+        assert!(matches!(lexer.next_token(), Ok(Some(Token::NumberValue(0)))));
+        assert!(matches!(lexer.next_token(), Ok(Some(Token::NumberValue(7)))));
+        match lexer.next_token() {
+            Ok(Some(Token::FloatValue(value))) => assert_eq!(value, 0.0),
+            other => assert!(false, "Expected a float token, got {:?}", other),
+        }
+        match lexer.next_token() {
+            Ok(Some(Token::FloatValue(value))) => {
+                assert_eq!(value, 0.0);
+                assert!(value.is_sign_negative());
+            },
+            other => assert!(false, "Expected a negative zero, got {:?}", other),
+        }
+
+        // A number ending exactly at the end of data is not dropped
+        let data = String::from("42");
+        let mut lexer = Lexer::new(&data);
+        assert!(matches!(lexer.next_token(), Ok(Some(Token::NumberValue(42)))));
+        assert!(matches!(lexer.next_token(), Ok(None)));
+    }
+
+    #[test]
+    fn trailing_data_is_flagged_in_strict_mode() {
+        use parser_sample::parser::ParseError;
+
+        // Lax by default: a second concatenated document is silently consumed
+        let data = String::from("[{\"symbol\":\"A\"}][{\"symbol\":\"B\"}]");
+        let mut parser = Parser::new(&data);
+        assert!(matches!(parser.parse_single(), Ok(_)));
+        assert!(matches!(parser.parse_single(), Ok(_)));
+
+        // Strict mode reports anything following the closing bracket
+        let mut parser = Parser::new(&data);
+        parser.set_fail_on_trailing_data(true);
+        assert!(matches!(parser.parse_single(), Ok(_)));
+        assert!(matches!(parser.parse_single(), Err(ParseError::TrailingData)));
+
+        // Garbage separated from the document by whitespace is still trailing
+        // data, not a lexing error blamed on the space in front of it
+        let data = String::from("[{\"symbol\":\"A\"}] garbage");
+        let mut parser = Parser::new(&data);
+        parser.set_fail_on_trailing_data(true);
+        assert!(matches!(parser.parse_single(), Ok(_)));
+        assert!(matches!(parser.parse_single(), Err(ParseError::TrailingData)));
+
+        // A clean document still ends with a plain end of data
+        let data = String::from("[{\"symbol\":\"A\"}]");
+        let mut parser = Parser::new(&data);
+        parser.set_fail_on_trailing_data(true);
+        assert!(matches!(parser.parse_single(), Ok(_)));
+        assert!(matches!(parser.parse_single(), Err(ParseError::EndOfData)));
+
+        // A trailing newline is tolerated even in strict mode; endpoints
+        // routinely terminate their responses with one
+        let data = String::from("[{\"symbol\":\"A\"}]\n");
+        let mut parser = Parser::new(&data);
+        parser.set_fail_on_trailing_data(true);
+        assert!(matches!(parser.parse_single(), Ok(_)));
+        assert!(matches!(parser.parse_single(), Err(ParseError::EndOfData)));
+    }
+
+    #[test]
+    fn whitespace_between_tokens_is_skipped() {
+        // A pretty-printed document parses the same as the compact form;
+        // whitespace inside string values is of course preserved
+        let data = String::from("[\n  {\n    \"symbol\": \"A B\",\r\n    \"tradeCount\":\t7\n  }\n]\n");
+        let mut parser = Parser::new(&data);
+        match parser.parse_single() {
+            Ok(entry) => {
+                assert_eq!(entry.symbol, "A B");
+                assert_eq!(entry.trade_count, 7);
+            },
+            Err(error) => panic!("pretty-printed document failed to parse: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn fetch_and_parse_works_against_a_mock_server() {
+        use parser_sample::fetch_and_parse;
+        use std::io::{Read, Write};
+
+        // A throwaway single-request HTTP server on a random loopback port
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let body = "[{\"symbol\":\"BTC-TEST-1\"},{\"symbol\":\"BTC-TEST-2\"}]";
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(), body);
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            // Drain the request headers before answering
+            let mut buffer = [0u8; 1024];
+            let _ = stream.read(&mut buffer).unwrap();
+            stream.write_all(response.as_bytes()).unwrap();
+        });
+
+        let entries = fetch_and_parse(&format!("http://127.0.0.1:{}/ticker", port)).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].symbol, "BTC-TEST-1");
+        assert_eq!(entries[1].symbol, "BTC-TEST-2");
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn field_aliases_normalize_renamed_keys() {
+        use std::collections::HashMap;
+
+        let data = String::from("[{\"symbol\":\"A\",\"vol\":\"8.45\"}]");
+        let mut parser = Parser::new(&data);
+        let mut aliases = HashMap::new();
+        aliases.insert(String::from("vol"), String::from("volume"));
+        parser.set_field_aliases(aliases);
+
+        let entry = parser.parse_single().unwrap();
+        assert_eq!(entry.symbol, "A");
+        assert_eq!(entry.volume, 8.45);
+
+        // Without the alias the same document is an unrecognised key
+        let mut parser = Parser::new(&data);
+        match parser.parse_single() {
+            Err(ParseError::AtEntry{ index: 1, error }) => {
+                match *error {
+                    ParseError::UnrecognisedKeyStringValuePair{ ref key, .. } => assert_eq!(key, "vol"),
+                    ref other => assert!(false, "Expected an unrecognised key, got {:?}", other),
+                }
+            },
+            other => assert!(false, "Expected an entry-indexed error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn deep_nesting_hits_the_depth_limit_cleanly() {
+        use parser_sample::JsonValue;
+
+        // Far beyond the default limit of 128: an error, not a stack overflow
+        let data = "[".repeat(10_000);
+        let mut parser = Parser::new(&data);
+        match parser.parse_value() {
+            Err(ParseError::MaxDepthExceeded{ limit }) => assert_eq!(limit, 128),
+            other => assert!(false, "Expected the depth error, got {:?}", other),
+        }
+
+        // A lowered limit applies, and nesting below it still parses
+        let data = String::from("[[[[42]]]]");
+        let mut parser = Parser::new(&data);
+        parser.set_max_depth(3);
+        assert!(matches!(parser.parse_value(), Err(ParseError::MaxDepthExceeded{ limit: 3 })));
+
+        let mut parser = Parser::new(&data);
+        parser.set_max_depth(4);
+        let value = parser.parse_value().unwrap();
+        let expected = JsonValue::Array(vec![JsonValue::Array(vec![JsonValue::Array(vec![
+            JsonValue::Array(vec![JsonValue::Number(42)])])])]);
+        assert_eq!(value, expected);
+    }
+
+    #[test]
+    fn entries_round_trip_through_to_json() {
+        use parser_sample::entries_to_json;
+
+        let data = String::from(concat!(
+            "[{\"symbol\":\"BTC-TEST\",\"priceChange\":\"-10.5\",\"priceChangePercent\":\"-0.84\",",
+            "\"lastPrice\":\"2\",\"lastQty\":\"0\",\"open\":\"12.5\",\"high\":\"12.5\",",
+            "\"low\":\"2\",\"volume\":\"8.45\",\"amount\":\"46.58\",\"bidPrice\":\"1.9\",",
+            "\"askPrice\":\"3\",\"openTime\":1746897259343,\"closeTime\":1746937541235,",
+            "\"firstTradeId\":1,\"tradeCount\":8,\"strikePrice\":\"665\",\"exercisePrice\":\"665.12765896\"}]"));
+        let mut parser = Parser::new(&data);
+        let entry = parser.parse_single().unwrap();
+
+        // Serializing and re-parsing yields an identical entry
+        let json = entries_to_json(&[entry]);
+        let mut parser = Parser::new(&json);
+        let reparsed = parser.parse_single().unwrap();
+        assert_eq!(reparsed.to_json(), json[1..json.len() - 1]);
+        assert_eq!(reparsed.symbol, "BTC-TEST");
+        assert_eq!(reparsed.volume, 8.45);
+        assert_eq!(reparsed.trade_count, 8);
+
+        // Floats render without trailing-zero noise
+        assert!(reparsed.to_json().contains("\"lastPrice\":\"2\""));
+    }
+
+    #[test]
+    fn entry_offsets_point_at_the_opening_braces() {
+        let data = String::from("[{\"symbol\":\"A\"},{\"symbol\":\"B\"}]");
+        let mut parser = Parser::new(&data);
+
+        let (offset, entry) = parser.parse_single_with_offset().unwrap();
+        assert_eq!(entry.symbol, "A");
+        assert_eq!(offset, 1);
+        assert_eq!(&data[offset..offset + 1], "{");
+
+        // The second entry's offset matches the position of its brace
+        let (offset, entry) = parser.parse_single_with_offset().unwrap();
+        assert_eq!(entry.symbol, "B");
+        assert_eq!(offset, data.find(",").unwrap() + 1);
+        assert_eq!(&data[offset..offset + 1], "{");
+
+        // Re-parsing from a recorded offset yields the same entry
+        let mut parser = Parser::new(&data[offset..data.len() - 1]);
+        let reparsed = parser.parse_single().unwrap();
+        assert_eq!(reparsed.symbol, "B");
+    }
+
+    #[test]
+    fn skipping_an_entry_advances_to_the_next() {
+        let data = String::from("[{\"symbol\":\"A\",\"tradeCount\":8},{\"symbol\":\"B\"}]");
+        let mut parser = Parser::new(&data);
+
+        assert!(matches!(parser.skip_entry(), Ok(true)));
+        let entry = parser.parse_single().unwrap();
+        assert_eq!(entry.symbol, "B");
+
+        // Nothing left to skip once the array is exhausted
+        assert!(matches!(parser.skip_entry(), Ok(false)));
+    }
+
+    #[test]
+    fn over_long_strings_are_rejected_when_capped() {
+        let data = String::from("[{\"symbol\":\"BTC-LONG-SYMBOL-NAME\"}]");
+        let mut parser = Parser::new(&data);
+        parser.set_max_string_len(8);
+        match parser.parse_single() {
+            Err(ParseError::StringTooLong{ limit }) => assert_eq!(limit, 8),
+            other => assert!(false, "Expected the length error, got {:?}", other),
+        }
+
+        // A generous cap leaves ordinary documents untouched
+        let mut parser = Parser::new(&data);
+        parser.set_max_string_len(64);
+        let entry = parser.parse_single().unwrap();
+        assert_eq!(entry.symbol, "BTC-LONG-SYMBOL-NAME");
+
+        // The cap counts decoded characters: seven escaped newlines exceed a
+        // cap of six that the keys themselves stay within
+        let data = String::from("[{\"symbol\":\"\\n\\n\\n\\n\\n\\n\\n\"}]");
+        let mut parser = Parser::new(&data);
+        parser.set_max_string_len(6);
+        assert!(matches!(parser.parse_single(), Err(ParseError::StringTooLong{ limit: 6 })));
+    }
+
+    #[test]
+    fn parse_value_builds_a_tree() {
+        use parser_sample::JsonValue;
+
+        let data = String::from("[{\"a\":1,\"b\":[2,3]}]");
+        let mut parser = Parser::new(&data);
+
+        let value = match parser.parse_value() {
+            Ok(value) => value,
+            Err(error) => {
+                assert!(false, "parse_value produced an error: {}", error);
+                return;
+            }
+        };
+
+        let expected = JsonValue::Array(vec![
+            JsonValue::Object(vec![
+                (String::from("a"), JsonValue::Number(1)),
+                (String::from("b"), JsonValue::Array(vec![
+                    JsonValue::Number(2),
+                    JsonValue::Number(3),
+                ])),
+            ]),
+        ]);
+        assert_eq!(value, expected);
+    }
+
+    #[test]
+    fn a_leading_bom_is_skipped() {
+        let file_path = "./assets/body_text.json";
+        let file = match std::fs::read_to_string(file_path) {
+            Ok(file) => file,
+            Err(error) => {
+                assert!(false, "Reading the asset file failed: {}", error);
+                return;
+            }
+        };
+
+        let with_bom = format!("\u{feff}{}", file);
+        let mut parser = Parser::new(&with_bom);
+
+        let mut count = 0;
+        loop {
+            match parser.parse_single() {
+                Err(ParseError::EndOfData) => break,
+                Err(error) => assert!(false, "parse_single produced a non-EndOfData error: {}", error),
+                Ok(_) => count += 1,
+            }
+        }
+        assert_eq!(count, 1436);
+
+        // A BOM anywhere else remains an unrecognised token
+        let mut misplaced_parser = Parser::new("[\u{feff}]");
+        assert!(matches!(misplaced_parser.parse_single(), Err(ParseError::UnrecognisedToken{ character: '\u{feff}', .. })));
+    }
+
     #[test]
     fn parsing_entire_data_works() {
         let file_path = "./assets/body_text.json";
@@ -1,71 +1,264 @@
 
-use std::{fmt::Display, iter::Peekable, num::ParseFloatError};
+use std::{borrow::Cow, fmt::Display, iter::Peekable, num::ParseFloatError, num::ParseIntError};
 
 // Our idea for a parser is a direct scan of the characters
 // This gives us a lot of power on the exact parsing and when to stop it
 // First, let's define a suitable struct that represents the data:
 
+
+// The endpoint encodes its float fields as JSON strings; these helpers let the
+// optional serde derives mirror that, so serialized entries match the feed and
+// serde_json can read the raw sample for cross-validation
+#[cfg(feature = "serde")]
+mod float_as_string {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer, F: std::fmt::Display>(value: &F, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D, F>(deserializer: D) -> Result<F, D::Error>
+    where D: Deserializer<'de>, F: std::str::FromStr, F::Err: std::fmt::Display {
+        let value = String::deserialize(deserializer)?;
+        value.parse::<F>().map_err(serde::de::Error::custom)
+    }
+}
+
+// The float precision is a type parameter so memory-constrained consumers can
+// trade precision for footprint; the aliases below cover the common choices.
 #[derive(Clone, Debug)]
-pub struct ResultEntry {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(
+    serialize = "F: std::fmt::Display",
+    deserialize = "F: std::str::FromStr, F::Err: std::fmt::Display",
+)))]
+// The wire format stays camelCase; only the Rust-side field names changed
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct GenericResultEntry<F> {
   pub symbol: String,
-  pub priceChange: f64,
-  pub priceChangePercent: f64,
-  pub lastPrice: f64,
-  pub lastQty: f64,
+  #[cfg_attr(feature = "serde", serde(with = "float_as_string"))]
+  pub price_change: F,
+  #[cfg_attr(feature = "serde", serde(with = "float_as_string"))]
+  pub price_change_percent: F,
+  #[cfg_attr(feature = "serde", serde(with = "float_as_string"))]
+  pub last_price: F,
+  #[cfg_attr(feature = "serde", serde(with = "float_as_string"))]
+  pub last_qty: F,
+  #[cfg_attr(feature = "serde", serde(with = "float_as_string"))]
+  pub open: F,
+  #[cfg_attr(feature = "serde", serde(with = "float_as_string"))]
+  pub high: F,
+  #[cfg_attr(feature = "serde", serde(with = "float_as_string"))]
+  pub low: F,
+  #[cfg_attr(feature = "serde", serde(with = "float_as_string"))]
+  pub volume: F,
+  #[cfg_attr(feature = "serde", serde(with = "float_as_string"))]
+  pub amount: F,
+  #[cfg_attr(feature = "serde", serde(with = "float_as_string"))]
+  pub bid_price: F,
+  #[cfg_attr(feature = "serde", serde(with = "float_as_string"))]
+  pub ask_price: F,
+  pub open_time: u64,
+  pub close_time: u64,
+  pub first_trade_id: u64,
+  pub trade_count: u64,
+  #[cfg_attr(feature = "serde", serde(with = "float_as_string"))]
+  pub strike_price: F,
+  #[cfg_attr(feature = "serde", serde(with = "float_as_string"))]
+  pub exercise_price: F,
+  // Unknown keys captured as raw strings when capturing is enabled; not part
+  // of the wire format, hence excluded from the serde representation
+  #[cfg_attr(feature = "serde", serde(skip))]
+  pub extra: std::collections::HashMap<String, String>,
+  // The order the keys appeared in, recorded only when the parser is asked to
+  #[cfg_attr(feature = "serde", serde(skip))]
+  key_order: Vec<String>,
+}
+
+// The default entry type keeps full f64 precision
+pub type ResultEntry = GenericResultEntry<f64>;
+// A half-footprint variant for storing large batches
+pub type ResultEntryF32 = GenericResultEntry<f32>;
+
+
+impl<F: std::fmt::Display> GenericResultEntry<F> {
+    /// Renders the entry back into a compact JSON object under the original
+    /// camelCase wire keys, float fields quoted as the endpoint sends them.
+    /// The {} formatting of floats prints the shortest round-tripping form, so
+    /// values come out without trailing-zero noise.
+    /// @return The entry as a compact JSON object string
+    pub fn to_json(&self) -> String {
+        // The symbol is the only free-form string; escape the characters that
+        // would break the quoting
+        let symbol = self.symbol.replace('\\', "\\\\").replace('"', "\\\"");
+        return format!(
+            concat!(
+                "{{\"symbol\":\"{}\",\"priceChange\":\"{}\",\"priceChangePercent\":\"{}\",",
+                "\"lastPrice\":\"{}\",\"lastQty\":\"{}\",\"open\":\"{}\",\"high\":\"{}\",",
+                "\"low\":\"{}\",\"volume\":\"{}\",\"amount\":\"{}\",\"bidPrice\":\"{}\",",
+                "\"askPrice\":\"{}\",\"openTime\":{},\"closeTime\":{},\"firstTradeId\":{},",
+                "\"tradeCount\":{},\"strikePrice\":\"{}\",\"exercisePrice\":\"{}\"}}"),
+            symbol, self.price_change, self.price_change_percent,
+            self.last_price, self.last_qty, self.open, self.high,
+            self.low, self.volume, self.amount, self.bid_price,
+            self.ask_price, self.open_time, self.close_time, self.first_trade_id,
+            self.trade_count, self.strike_price, self.exercise_price);
+    }
+}
+
+/// Wraps the given entries into a JSON array string, the inverse of parse_all
+/// @return The entries as a compact JSON array string
+pub fn entries_to_json(entries: &[ResultEntry]) -> String {
+    let rendered: Vec<String> = entries.iter()
+        .map(|entry| { return entry.to_json(); })
+        .collect();
+    return format!("[{}]", rendered.join(","));
+}
+
+impl<F: Default> GenericResultEntry<F> {
+    /// The order the keys appeared in within the source object, populated only
+    /// when the parser records it (see Parser::set_record_key_order)
+    /// @return The keys in document order
+    pub fn key_order(&self) -> &[String] {
+        return &self.key_order;
+    }
+
+    pub fn new() -> Self {
+        GenericResultEntry { 
+            symbol: String::new(),
+            price_change: F::default(),
+            price_change_percent: F::default(),
+            last_price: F::default(),
+            last_qty: F::default(),
+            open: F::default(),
+            high: F::default(), 
+            low: F::default(), 
+            volume: F::default(), 
+            amount: F::default(), 
+            bid_price: F::default(), 
+            ask_price: F::default(), 
+            open_time: 0,
+            close_time: 0,
+            first_trade_id: 0, 
+            trade_count: 0, 
+            strike_price: F::default(), 
+            exercise_price: F::default(),
+            extra: std::collections::HashMap::new(),
+            key_order: Vec::new(),
+        }
+    }
+}
+
+
+// A borrowing counterpart to ResultEntry for the hot path: the symbol is a span
+// borrowed straight from the input data whenever it contains no escapes, so
+// parsing an entry does not have to allocate at all
+#[derive(Clone, Debug)]
+pub struct RawEntry<'data> {
+  pub symbol: Cow<'data, str>,
+  pub price_change: f64,
+  pub price_change_percent: f64,
+  pub last_price: f64,
+  pub last_qty: f64,
   pub open: f64,
   pub high: f64,
   pub low: f64,
   pub volume: f64,
   pub amount: f64,
-  pub bidPrice: f64,
-  pub askPrice: f64,
-  pub openTime: usize,
-  pub closeTime: usize,
-  pub firstTradeId: usize,
-  pub tradeCount: usize,
-  pub strikePrice: f64,
-  pub exercisePrice: f64,
+  pub bid_price: f64,
+  pub ask_price: f64,
+  pub open_time: u64,
+  pub close_time: u64,
+  pub first_trade_id: u64,
+  pub trade_count: u64,
+  pub strike_price: f64,
+  pub exercise_price: f64,
 }
 
-
-impl ResultEntry {
+impl<'data> RawEntry<'data> {
     pub fn new() -> Self {
-        ResultEntry { 
-            symbol: String::new(),
-            priceChange: 0.0,
-            priceChangePercent: 0.0,
-            lastPrice: 0.0,
-            lastQty: 0.0,
+        RawEntry {
+            symbol: Cow::Borrowed(""),
+            price_change: 0.0,
+            price_change_percent: 0.0,
+            last_price: 0.0,
+            last_qty: 0.0,
             open: 0.0,
-            high: 0.0, 
-            low: 0.0, 
-            volume: 0.0, 
-            amount: 0.0, 
-            bidPrice: 0.0, 
-            askPrice: 0.0, 
-            openTime: 0,
-            closeTime: 0,
-            firstTradeId: 0, 
-            tradeCount: 0, 
-            strikePrice: 0.0, 
-            exercisePrice: 0.0,
+            high: 0.0,
+            low: 0.0,
+            volume: 0.0,
+            amount: 0.0,
+            bid_price: 0.0,
+            ask_price: 0.0,
+            open_time: 0,
+            close_time: 0,
+            first_trade_id: 0,
+            trade_count: 0,
+            strike_price: 0.0,
+            exercise_price: 0.0,
         }
     }
 }
 
-// An error enum that represents all errors that can occur during lexing
-enum ParseTokenError {
-    EndOfData, // There is no data left to be parsed
-    UnrecognisedToken(char), // There was an unexpected token encountered
+/// A position inside the data being parsed, for pinpointing errors in large bodies.
+/// Line and column are 1-based; the offset counts bytes from the start of the data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub offset: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Position {
+    fn start() -> Self {
+        Position { offset: 0, line: 1, column: 1 }
+    }
+
+    /// Moves the position past the given character
+    fn advance(&mut self, character: char) {
+        self.offset += character.len_utf8();
+        if character == '\n' {
+            self.line += 1;
+            self.column = 1;
+        }
+        else {
+            self.column += 1;
+        }
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
 }
 
 // An error enum that represents all errors that can occur during parsing
+#[derive(Debug)]
 pub enum ParseError {
     EndOfData, // There is no data left to be parsed
-    UnrecognisedToken(char), // There was an unexpected token encountered
+    NeedMoreData, // A fed parser ran out of input mid-entry; feed more data and retry
+    EntryLimitReached, // The configured maximum number of entries was already parsed
+    PendingPeek, // A peeked entry is still buffered; only parse_single can deliver it in order
+    EmptySymbol, // An object provided an empty symbol, which this endpoint never does legitimately
+    UnrecognisedToken{ character: char, position: Position }, // There was an unexpected token encountered
+    InvalidEscape(char), // A '\' was followed by a character that does not form a valid JSON escape
     UnrecognisedKeyStringValuePair{ key: String, value: String }, // An unrecognised key with a string value was found
-    UnrecognisedKeyNumberValuePair{ key: String, value: usize }, // An unrecognised key with a number value was found
+    UnrecognisedKeyNumberValuePair{ key: String, value: u64 }, // An unrecognised key with a number value was found
+    UnrecognisedKeyBoolValuePair{ key: String, value: bool }, // An unrecognised key with a boolean value was found
+    MissingFields{ missing: Vec<String> }, // An object closed without providing all required keys
+    DuplicateKey{ key: String }, // The same key appeared twice within one object
+    UnexpectedToken{ token_description: String, state_description: String }, // A structurally valid token appeared where the grammar does not allow it
+    TrailingData, // In strict mode, data continued after the document's closing bracket
+    MaxDepthExceeded{ limit: usize }, // A value nested deeper than the configured maximum
+    AtEntry{ index: usize, error: Box<ParseError> }, // A parse error, annotated with the 1-based index of the array entry it occurred in
+    UnterminatedString(String), // The data ended inside a string; carries the partial content, e.g. from a truncated HTTP body
+    StringTooLong{ limit: usize }, // A string value grew beyond the configured maximum length
     ParseFloatError{ key: String, value: String, error: ParseFloatError}, // An expected float point value could not be parsed as such
+    ParseIntError{ value: String, error: ParseIntError }, // An unquoted number was too large to fit our integer type
+    InvalidUnicodeEscape(String), // A \uXXXX sequence contained malformed hex or an unpaired surrogate
+    InvalidUtf8(std::str::Utf8Error), // The byte input was not valid UTF-8
+    IoError(std::io::Error), // Reading from an underlying reader failed
 }
 
 // Pretty printing for our ParseError
@@ -75,8 +268,20 @@ impl Display for ParseError {
             &ParseError::EndOfData => {
                 write!(f, "The end of data was reached.")
             },
-            &ParseError::UnrecognisedToken(ref string) => {
-                write!(f, "An unrecognised token {} was encountered.", string)
+            &ParseError::NeedMoreData => {
+                write!(f, "The data fed so far ends in the middle of an entry.")
+            },
+            &ParseError::EntryLimitReached => {
+                write!(f, "The configured entry limit was reached.")
+            },
+            &ParseError::PendingPeek => {
+                write!(f, "A peeked entry is still buffered; parse_single must deliver it first.")
+            },
+            &ParseError::EmptySymbol => {
+                write!(f, "An object provided an empty symbol.")
+            },
+            &ParseError::UnrecognisedToken{ ref character, ref position } => {
+                write!(f, "An unrecognised token {} was encountered at {}.", character, position)
             },
             &ParseError::UnrecognisedKeyStringValuePair{ref key, ref value} => {
                 write!(f, "Unexpected key {} found with string value {}", key, value)
@@ -84,29 +289,113 @@ impl Display for ParseError {
             &ParseError::UnrecognisedKeyNumberValuePair{ ref key, ref value } => {
                 write!(f, "Unexpected key {} found with number value {}", key, value)
             },
+            &ParseError::UnrecognisedKeyBoolValuePair{ ref key, ref value } => {
+                write!(f, "Unexpected key {} found with boolean value {}", key, value)
+            },
+            &ParseError::MissingFields{ ref missing } => {
+                write!(f, "An object closed without the required keys: {}", missing.join(", "))
+            },
+            &ParseError::DuplicateKey{ ref key } => {
+                write!(f, "The key {} appeared twice within a single object.", key)
+            },
             &ParseError::ParseFloatError{ ref key, ref value, ref error} => {
                 write!(f, "Key entry {} with string value \"{}\" could not be parsed as float: {}", key, value, error)
             },
+            &ParseError::ParseIntError{ ref value, ref error } => {
+                write!(f, "Number value \"{}\" could not be parsed as an integer: {}", value, error)
+            },
+            &ParseError::TrailingData => {
+                write!(f, "Data continues after the document's closing bracket.")
+            },
+            &ParseError::MaxDepthExceeded{ ref limit } => {
+                write!(f, "The value nesting exceeds the configured maximum depth of {}.", limit)
+            },
+            &ParseError::UnexpectedToken{ ref token_description, ref state_description } => {
+                write!(f, "An unexpected token {} was encountered in state {}.", token_description, state_description)
+            },
+            &ParseError::AtEntry{ ref index, ref error } => {
+                write!(f, "Entry {}: {}", index, error)
+            },
+            &ParseError::StringTooLong{ ref limit } => {
+                write!(f, "A string value exceeds the configured maximum length of {}.", limit)
+            },
+            &ParseError::UnterminatedString(ref partial) => {
+                write!(f, "The data ended inside the string \"{}\" before its closing quote.", partial)
+            },
+            &ParseError::InvalidEscape(ref character) => {
+                write!(f, "An invalid escape sequence \\{} was encountered inside a string.", character)
+            },
+            &ParseError::InvalidUnicodeEscape(ref sequence) => {
+                write!(f, "An invalid unicode escape sequence \\u{} was encountered inside a string.", sequence)
+            },
+            &ParseError::InvalidUtf8(ref error) => {
+                write!(f, "The data given is not valid UTF-8: {}", error)
+            },
+            &ParseError::IoError(ref error) => {
+                write!(f, "Reading from the underlying reader failed: {}", error)
+            },
+        }
+    }
+}
+
+// Implementing std::error::Error makes ParseError composable with the wider
+// error handling ecosystem, e.g. boxing into Box<dyn Error> or use with `?`
+impl std::error::Error for ParseError {
+    /// @return The underlying error for variants that wrap one, None otherwise
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            &ParseError::ParseFloatError{ ref error, .. } => Some(error),
+            &ParseError::ParseIntError{ ref error, .. } => Some(error),
+            &ParseError::InvalidUtf8(ref error) => Some(error),
+            &ParseError::IoError(ref error) => Some(error),
+            &ParseError::AtEntry{ ref error, .. } => Some(error),
+            _ => None,
         }
     }
 }
 
-// An enum to represent the lexical tokens we are looking for in the data:
+// An enum to represent the lexical tokens we are looking for in the data.
+// Both the tokens and the Lexer below are public so custom parsing logic for
+// differently shaped payloads can be built on top of the same lexing layer.
 #[derive(Debug)]
-enum Token {
+pub enum Token<'data> {
     ArrayStart, // '[' marking the beginning of a JSON data array
     ArrayEnd, // ']' marking the end of a JSON data array
     ObjectStart, // '{' marking the beginning of a JSON data object
     ObjectEnd, // '}' marking the end of a JSON data object
-    StringValue(String), // "sometext", the data containing all characters within the '"' span
-    NumberValue(usize), // 1353426, data not marked with a '"' but restricted to a series of digits
+    StringValue(Cow<'data, str>), // "sometext", borrowed straight from in-memory data when no escapes appear
+    NumberValue(u64), // 1353426, data not marked with a '"' but restricted to a series of digits
+    FloatValue(f64), // 1.5e+3, an unquoted number with a fraction, an exponent or a leading minus
+    BoolValue(bool), // the bare keywords 'true' and 'false'
+    Null, // the bare keyword 'null'
     //KeyIdentifier // ':', can be ignored
     //DataSeparator // ',', can be ignored
 }
 
 
+// A generic JSON value tree for payloads whose schema is not known in advance.
+// Object members keep their original order as key value pairs.
+#[derive(Clone, Debug, PartialEq)]
+pub enum JsonValue {
+    Object(Vec<(String, JsonValue)>),
+    Array(Vec<JsonValue>),
+    String(String),
+    Number(u64),
+    Float(f64),
+    Bool(bool),
+    Null,
+}
+
+// One level of an in-progress parse_value tree. The explicit stack of frames
+// replaces recursion, bounding nesting by the configured limit instead of the
+// call stack.
+enum ValueFrame {
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>, Option<String>), // The members so far, plus the key awaiting its value
+}
+
 // A few state machine states to represent the circumstances after each token:
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 enum State {
     // [{"key": "value"}]
     //^                 ^
@@ -123,175 +412,282 @@ enum State {
     Key(String),
 }
 
-// Of course, this is way more complicated than using Serde for example
-// But this also gives us the power of optimizing the entirety of the algorithm
-// Let's define our parser as a struct that borrows data with lifetime 'data
-#[derive(Debug)]
-pub struct Parser<'data>{
-    state: State,
-    char_iterator: Peekable<std::str::Chars<'data>>,
-    current_entry: ResultEntry,
-}
+// How many bytes we pull from a reader at a time before decoding them
+const READ_CHUNK_SIZE: usize = 8 * 1024;
 
-// Note that we are making an assumption here: the body is encoded in standard ASCI characters
-// and one character always corresponds to one "letter".
-impl<'data> Parser<'data> {
-    /// Create a new Parser that borrows data from the String given
-    pub fn new(data_as_string: &'data String) -> Self {
-        Parser{
-            state: State::Init,
-            char_iterator: data_as_string.chars().peekable(),
-            current_entry: ResultEntry::new(),
-        }
-    }
+// A source that decodes characters chunk-wise from an arbitrary reader.
+// Bytes are read into an undecoded buffer; the longest valid UTF-8 prefix is
+// decoded into pending characters while an incomplete trailing sequence is kept
+// for the next chunk.
+struct ReaderSource<'data> {
+    reader: Box<dyn std::io::Read + 'data>,
+    undecoded: Vec<u8>,
+    decoded: std::collections::VecDeque<char>,
+    finished: bool,
+    error: Option<ParseError>,
+}
 
-    /// Consumes the next token from our current data stream
-    /// @return A token if the next token could be parsed successfully, an error otherwise (including end of data)
-    fn consume_token(&mut self) -> Result<Token, ParseTokenError> {
-        while let Some(character) = self.char_iterator.next() {
-            match character {
-                '[' => {
-                    return Ok(Token::ArrayStart)
+impl<'data> ReaderSource<'data> {
+    /// Pulls and decodes more data until at least one character is pending,
+    /// the reader is exhausted or an error occured
+    fn refill(&mut self) {
+        while self.decoded.is_empty() && !self.finished {
+            let mut chunk = [0u8; READ_CHUNK_SIZE];
+            match self.reader.read(&mut chunk) {
+                Ok(0) => self.finished = true,
+                Ok(count) => self.undecoded.extend_from_slice(&chunk[..count]),
+                Err(error) => {
+                    self.error = Some(ParseError::IoError(error));
+                    self.finished = true;
                 },
-                ']' => {
-                    return Ok(Token::ArrayEnd)
-                },
-                '{' => {
-                    return Ok(Token::ObjectStart)
-                },
-                '}' => {
-                    return Ok(Token::ObjectEnd)
+            }
+
+            match std::str::from_utf8(&self.undecoded) {
+                Ok(valid) => {
+                    self.decoded.extend(valid.chars());
+                    self.undecoded.clear();
                 },
-                ',' | ':' => {
-                    // Purposefully skip key identifiers and separators
-                    continue;
-                }
-                '"' => {
-                    // Parse a string: any character is accepted until next occurence of '"'
-                    let mut value = String::new();
-                    while let Some(string_character) = self.char_iterator.next() {
-                        if string_character == '"' {
-                            break;
-                        }
-                        value.push(string_character);
+                Err(error) => {
+                    let valid = std::str::from_utf8(&self.undecoded[..error.valid_up_to()]).unwrap();
+                    self.decoded.extend(valid.chars());
+                    if error.error_len().is_some() {
+                        // An actually invalid sequence, not just an incomplete one
+                        self.error = Some(ParseError::InvalidUtf8(error));
+                        self.finished = true;
+                        self.undecoded.clear();
                     }
-                    return Ok(Token::StringValue(value));
-                },
-                '0' | '1' | '2' | '3' |  '4' |  '5' |  '6' |  '7' |  '8' |  '9' => {
-                    // Parse a number string: add characters until a non-digit appears
-                    // Important here is to not consume the first non-digit character
-                    let mut number_value = String::new();
-                    number_value.push(character);
-                    while let Some(number_character) = self.char_iterator.peek() {
-                        match number_character {
-                            '0' | '1' | '2' | '3' |  '4' |  '5' |  '6' |  '7' |  '8' |  '9' => {
-                                number_value.push(number_character.clone());
-                                self.char_iterator.next();
-                            },
-                            _ => {
-                                // We're making the bold assertion here that a string of number characters will never fail to parse into a usize
-                                return Ok(Token::NumberValue(number_value.parse::<usize>().unwrap()));
-                            }
+                    else {
+                        // An incomplete trailing sequence: keep it for the next chunk
+                        self.undecoded.drain(..error.valid_up_to());
+                        // Unless the reader is already exhausted, then it is truncated data
+                        if self.finished {
+                            self.error = Some(ParseError::InvalidUtf8(error));
+                            self.undecoded.clear();
                         }
                     }
-                }
-                _ => {
-                    return Err(ParseTokenError::UnrecognisedToken(character));
                 },
             }
         }
+    }
+}
+
+// A source over an owned, growable buffer that callers append to with feed().
+// The cursor is a byte offset into the buffer marking the next character to serve.
+struct FedSource {
+    buffer: String,
+    cursor: usize,
+}
+
+// The different origins our character stream can be pulled from
+enum CharSource<'data> {
+    // Borrowed in-memory data
+    Str(Peekable<std::str::Chars<'data>>),
+    // Lazily decoded data from a reader
+    Reader(ReaderSource<'data>),
+    // Incrementally fed in-memory data
+    Fed(FedSource),
+    // An arbitrary stream of already-decoded characters
+    Chars(Peekable<Box<dyn Iterator<Item = char> + 'data>>),
+}
+
+impl<'data> CharSource<'data> {
+    /// Consumes and returns the next character of the stream
+    fn next_character(&mut self) -> Option<char> {
+        match self {
+            CharSource::Str(iterator) => iterator.next(),
+            CharSource::Reader(source) => {
+                source.refill();
+                source.decoded.pop_front()
+            },
+            CharSource::Fed(source) => {
+                let character = source.buffer[source.cursor..].chars().next();
+                if let Some(character) = character {
+                    source.cursor += character.len_utf8();
+                }
+                character
+            },
+            CharSource::Chars(iterator) => iterator.next(),
+        }
+    }
+
+    /// Returns the next character of the stream without consuming it
+    fn peek_character(&mut self) -> Option<char> {
+        match self {
+            CharSource::Str(iterator) => iterator.peek().cloned(),
+            CharSource::Reader(source) => {
+                source.refill();
+                source.decoded.front().cloned()
+            },
+            CharSource::Fed(source) => source.buffer[source.cursor..].chars().next(),
+            CharSource::Chars(iterator) => iterator.peek().cloned(),
+        }
+    }
+
+    /// Takes a pending source-level error, if any. Consulted when the stream
+    /// runs dry to distinguish a clean end of data from a failure.
+    fn take_error(&mut self) -> Option<ParseError> {
+        match self {
+            CharSource::Str(_) => None,
+            CharSource::Reader(source) => source.error.take(),
+            CharSource::Fed(_) => None,
+            CharSource::Chars(_) => None,
+        }
+    }
+}
+
+// A compact single-line rendering for logs, limited to the fields one
+// usually scans for; the derived Debug output stays available for full dumps
+impl<F: Display> Display for GenericResultEntry<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} last={} vol={}", self.symbol, self.last_price, self.volume)
+    }
+}
+
+// Implementing Default alongside new() lets generic code construct a fresh entry
+impl<F: Default> Default for GenericResultEntry<F> {
+    fn default() -> Self {
+        return GenericResultEntry::new();
+    }
+}
+
+/// A trait for structs that can be filled from the key value pairs of a flat
+/// JSON object. Implementing it makes a struct usable with parse_single_into,
+/// decoupling the parser from the hardcoded ResultEntry schema.
+pub trait FromJsonObject: Default {
+    /// Accept a string value for the given key
+    fn set_string(&mut self, key: &str, value: String) -> Result<(), ParseError>;
+
+    /// Accept an unquoted number value for the given key
+    fn set_number(&mut self, key: &str, value: u64) -> Result<(), ParseError>;
+
+    /// Accept a boolean value for the given key. By default booleans are routed
+    /// to the unrecognised key error since most schemas here have no boolean fields.
+    fn set_bool(&mut self, key: &str, value: bool) -> Result<(), ParseError> {
+        return Err(ParseError::UnrecognisedKeyBoolValuePair { key: String::from(key), value, });
+    }
 
-        return Err(ParseTokenError::EndOfData);
+    /// Called for keys none of the typed setters recognised when the parser is
+    /// capturing unknown keys. The default implementation discards them.
+    fn set_extra(&mut self, _key: &str, _value: String) {}
+
+    /// The keys that must be present in every object for the entry to count as
+    /// complete. Only consulted when the parser checks for missing fields.
+    /// @return The list of required keys, empty by default
+    fn required_keys() -> &'static [&'static str] {
+        return &[];
+    }
+
+    /// The symbol carried by the entry, consulted by the parser's symbol filter
+    /// and empty-symbol validation. Types without a symbol field return None,
+    /// which makes both features pass entries through untouched.
+    /// @return The symbol if the type carries one
+    fn symbol(&self) -> Option<&str> {
+        return None;
     }
 
+    /// Called with each key in document order when the parser was asked to
+    /// record key order. The default implementation discards the keys.
+    fn record_key(&mut self, _key: &str) {}
+}
+
+impl<F: std::str::FromStr<Err = ParseFloatError> + Default> FromJsonObject for GenericResultEntry<F> {
     /// Set data of given entry according to JSON key string value pair
     /// @return Ok(()) if given key value pair is a valid entry, otherwise an error specifying the issue
-    fn set_data_from_string(entry: &mut ResultEntry, key: &String, value: String) -> Result<(), ParseError>{
-        match key.as_str() {
+    fn set_string(&mut self, key: &str, value: String) -> Result<(), ParseError> {
+        match key {
             "symbol" => {
-                entry.symbol = value;
+                self.symbol = value;
             },
             "priceChange" => {
-                match value.parse::<f64>() {
-                    Ok(value_f64) => entry.priceChange = value_f64,
-                    Err(error) => return Err(ParseError::ParseFloatError{ key: key.clone(), value, error, }),
+                match value.parse::<F>() {
+                    Ok(value_f64) => self.price_change = value_f64,
+                    Err(error) => return Err(ParseError::ParseFloatError{ key: String::from(key), value, error, }),
                 }
             },
             "priceChangePercent" => {
-                match value.parse::<f64>() {
-                    Ok(value_f64) => entry.priceChangePercent = value_f64,
-                    Err(error) => return Err(ParseError::ParseFloatError{ key: key.clone(), value, error, }),
+                match value.parse::<F>() {
+                    Ok(value_f64) => self.price_change_percent = value_f64,
+                    Err(error) => return Err(ParseError::ParseFloatError{ key: String::from(key), value, error, }),
                 }
             },
             "lastPrice" => {
-                match value.parse::<f64>() {
-                    Ok(value_f64) => entry.lastPrice = value_f64,
-                    Err(error) => return Err(ParseError::ParseFloatError{ key: key.clone(), value, error, }),
+                match value.parse::<F>() {
+                    Ok(value_f64) => self.last_price = value_f64,
+                    Err(error) => return Err(ParseError::ParseFloatError{ key: String::from(key), value, error, }),
                 }
             },
             "lastQty" => {
-                match value.parse::<f64>() {
-                    Ok(value_f64) => entry.lastQty = value_f64,
-                    Err(error) => return Err(ParseError::ParseFloatError{ key: key.clone(), value, error, }),
+                match value.parse::<F>() {
+                    Ok(value_f64) => self.last_qty = value_f64,
+                    Err(error) => return Err(ParseError::ParseFloatError{ key: String::from(key), value, error, }),
                 }
             },
             "open" => {
-                match value.parse::<f64>() {
-                    Ok(value_f64) => entry.open = value_f64,
-                    Err(error) => return Err(ParseError::ParseFloatError{ key: key.clone(), value, error, }),
+                match value.parse::<F>() {
+                    Ok(value_f64) => self.open = value_f64,
+                    Err(error) => return Err(ParseError::ParseFloatError{ key: String::from(key), value, error, }),
                 }
             },
             "high" => {
-                match value.parse::<f64>() {
-                    Ok(value_f64) => entry.high = value_f64,
-                    Err(error) => return Err(ParseError::ParseFloatError{ key: key.clone(), value, error, }),
+                match value.parse::<F>() {
+                    Ok(value_f64) => self.high = value_f64,
+                    Err(error) => return Err(ParseError::ParseFloatError{ key: String::from(key), value, error, }),
                 }
             },
             "low" => {
-                match value.parse::<f64>() {
-                    Ok(value_f64) => entry.low = value_f64,
-                    Err(error) => return Err(ParseError::ParseFloatError{ key: key.clone(), value, error, }),
+                match value.parse::<F>() {
+                    Ok(value_f64) => self.low = value_f64,
+                    Err(error) => return Err(ParseError::ParseFloatError{ key: String::from(key), value, error, }),
                 }
             },
             "volume" => {
-                match value.parse::<f64>() {
-                    Ok(value_f64) => entry.volume = value_f64,
-                    Err(error) => return Err(ParseError::ParseFloatError{ key: key.clone(), value, error, }),
+                match value.parse::<F>() {
+                    Ok(value_f64) => self.volume = value_f64,
+                    Err(error) => return Err(ParseError::ParseFloatError{ key: String::from(key), value, error, }),
                 }
             },
             "amount" => {
-                match value.parse::<f64>() {
-                    Ok(value_f64) => entry.amount = value_f64,
-                    Err(error) => return Err(ParseError::ParseFloatError{ key: key.clone(), value, error, }),
+                match value.parse::<F>() {
+                    Ok(value_f64) => self.amount = value_f64,
+                    Err(error) => return Err(ParseError::ParseFloatError{ key: String::from(key), value, error, }),
                 }
             },
             "bidPrice" => {
-                match value.parse::<f64>() {
-                    Ok(value_f64) => entry.bidPrice = value_f64,
-                    Err(error) => return Err(ParseError::ParseFloatError{ key: key.clone(), value, error, }),
+                match value.parse::<F>() {
+                    Ok(value_f64) => self.bid_price = value_f64,
+                    Err(error) => return Err(ParseError::ParseFloatError{ key: String::from(key), value, error, }),
                 }
             },
             "askPrice" => {
-                match value.parse::<f64>() {
-                    Ok(value_f64) => entry.askPrice = value_f64,
-                    Err(error) => return Err(ParseError::ParseFloatError{ key: key.clone(), value, error, }),
+                match value.parse::<F>() {
+                    Ok(value_f64) => self.ask_price = value_f64,
+                    Err(error) => return Err(ParseError::ParseFloatError{ key: String::from(key), value, error, }),
                 }
             },
             "strikePrice" => {
-                match value.parse::<f64>() {
-                    Ok(value_f64) => entry.strikePrice = value_f64,
-                    Err(error) => return Err(ParseError::ParseFloatError{ key: key.clone(), value, error, }),
+                match value.parse::<F>() {
+                    Ok(value_f64) => self.strike_price = value_f64,
+                    Err(error) => return Err(ParseError::ParseFloatError{ key: String::from(key), value, error, }),
                 }
             },
             "exercisePrice" => {
-                match value.parse::<f64>() {
-                    Ok(value_f64) => entry.exercisePrice = value_f64,
-                    Err(error) => return Err(ParseError::ParseFloatError{ key: key.clone(), value, error, }),
+                match value.parse::<F>() {
+                    Ok(value_f64) => self.exercise_price = value_f64,
+                    Err(error) => return Err(ParseError::ParseFloatError{ key: String::from(key), value, error, }),
+                }
+            },
+
+            // The numeric IDs occasionally arrive quoted; accept them as
+            // strings too, mirroring how the float fields do
+            "firstTradeId" | "tradeCount" | "openTime" | "closeTime" => {
+                match value.parse::<u64>() {
+                    Ok(value_u64) => return self.set_number(key, value_u64),
+                    Err(error) => return Err(ParseError::ParseIntError{ value, error, }),
                 }
             },
 
             _ => {
-                return Err(ParseError::UnrecognisedKeyStringValuePair { key: key.clone(), value, });
+                return Err(ParseError::UnrecognisedKeyStringValuePair { key: String::from(key), value, });
             }
         }
 
@@ -300,81 +696,1766 @@ impl<'data> Parser<'data> {
 
     /// Set data of given entry according to JSON key number value pair
     /// @return Ok(()) if given key value pair is a valid entry, otherwise an error specifying the issue
-    fn set_data_from_number(entry: &mut ResultEntry, key: &String, value: usize) -> Result<(), ParseError> {
-        match key.as_str() {
+    fn set_number(&mut self, key: &str, value: u64) -> Result<(), ParseError> {
+        match key {
             "firstTradeId" => {
-                entry.firstTradeId = value;
+                self.first_trade_id = value;
             },
             "tradeCount" => {
-                entry.tradeCount = value;
+                self.trade_count = value;
             },
             "openTime" => {
-                entry.openTime = value;
+                self.open_time = value;
             },
             "closeTime" => {
-                entry.closeTime = value;
+                self.close_time = value;
             },
 
             _ => {
-                return Err(ParseError::UnrecognisedKeyNumberValuePair { key: key.clone(), value, });
+                return Err(ParseError::UnrecognisedKeyNumberValuePair { key: String::from(key), value, });
             }
         }
 
         return Ok(());
     }
 
-    /// Parses until the first ResultEntry was found
-    /// @return ResultEntry if there is data left, an error otherwise (including end of data)
-    pub fn parse_single(&mut self) -> Result<ResultEntry, ParseError> {
-        loop {
-            let token = match self.consume_token() {
-                Err(ParseTokenError::EndOfData) => break,
-                Err(ParseTokenError::UnrecognisedToken(character)) => return Err(ParseError::UnrecognisedToken(character)),
-                Ok(token) => token,
-            };
-        
-            match (&self.state, token) {
-                (&State::Init, Token::ArrayStart) => {
-                    self.state = State::Array;
-                },
+    /// Set data of given entry according to JSON key boolean value pair
+    /// @return Ok(()) if given key value pair is a valid entry, otherwise an error specifying the issue
+    fn set_bool(&mut self, key: &str, value: bool) -> Result<(), ParseError> {
+        // ResultEntry has no boolean fields today; route any boolean to the
+        // unrecognised key error rather than failing at the lexer level
+        return Err(ParseError::UnrecognisedKeyBoolValuePair { key: String::from(key), value, });
+    }
 
-                (&State::Array, Token::ObjectStart) => {
-                    self.state = State::Object;
-                },
-                (&State::Array, Token::ArrayEnd) => {
-                    self.state = State::Init;
-                },
+    fn set_extra(&mut self, key: &str, value: String) {
+        self.extra.insert(String::from(key), value);
+    }
 
-                (&State::Object, Token::StringValue(key)) => {
-                    self.state = State::Key(key);
-                },
-                (&State::Object, Token::ObjectEnd) => {
-                    self.state = State::Array;
-                    let entry = self.current_entry.clone();
-                    self.current_entry = ResultEntry::new();
-                    return Ok(entry);
-                },
+    fn required_keys() -> &'static [&'static str] {
+        return &[
+            "symbol", "priceChange", "priceChangePercent", "lastPrice", "lastQty",
+            "open", "high", "low", "volume", "amount", "bidPrice", "askPrice",
+            "openTime", "closeTime", "firstTradeId", "tradeCount", "strikePrice",
+            "exercisePrice",
+        ];
+    }
 
-                (&State::Key(ref key), Token::StringValue(value)) => {
-                    if let Err(error) = Self::set_data_from_string(&mut self.current_entry, key, value) {
-                        return Err(error);
-                    }
-                    self.state = State::Object;
-                },
+    fn symbol(&self) -> Option<&str> {
+        return Some(self.symbol.as_str());
+    }
 
-                (&State::Key(ref key), Token::NumberValue(value)) => {
-                    if let Err(error) = Self::set_data_from_number(&mut self.current_entry, key, value) {
-                        return Err(error);
+    fn record_key(&mut self, key: &str) {
+        self.key_order.push(String::from(key));
+    }
+
+}
+
+// The lexer turns the character stream into a stream of tokens. It is usable on
+// its own, decoupled from the ResultEntry schema the Parser below is wired to.
+// Everything needed to rewind a fed lexer to the start of an entry
+struct LexerSnapshot {
+    cursor: usize,
+    position: Position,
+    last_position: Position,
+}
+
+pub struct Lexer<'data> {
+    source: CharSource<'data>,
+    data: Option<&'data str>, // The full in-memory data, if there is one, for borrowing string spans
+    position: Position, // Position of the next character to be consumed
+    last_position: Position, // Position of the most recently consumed character
+    max_string_len: Option<usize>, // When set, a string longer than this is an error
+}
+
+impl<'data> Lexer<'data> {
+    /// Create a new Lexer that borrows the data given
+    pub fn new(data: &'data str) -> Self {
+        Lexer{
+            source: CharSource::Str(data.chars().peekable()),
+            data: Some(data),
+            position: Position::start(),
+            last_position: Position::start(),
+            max_string_len: None,
+        }
+    }
+
+    /// Create a new Lexer that pulls its data lazily from the reader given
+    pub fn from_reader<R: std::io::Read + 'data>(reader: R) -> Self {
+        Lexer{
+            source: CharSource::Reader(ReaderSource{
+                reader: Box::new(reader),
+                undecoded: Vec::new(),
+                decoded: std::collections::VecDeque::new(),
+                finished: false,
+                error: None,
+            }),
+            data: None,
+            position: Position::start(),
+            last_position: Position::start(),
+            max_string_len: None,
+        }
+    }
+
+    /// Creates a lexer over an arbitrary stream of characters, decoupling the
+    /// input from in-memory strings entirely (e.g. a decompressor yielding chars)
+    pub fn from_chars(chars: impl Iterator<Item = char> + 'data) -> Self {
+        Lexer{
+            source: CharSource::Chars((Box::new(chars) as Box<dyn Iterator<Item = char> + 'data>).peekable()),
+            data: None,
+            position: Position::start(),
+            last_position: Position::start(),
+            max_string_len: None,
+        }
+    }
+
+    /// Create a new Lexer over an initially empty owned buffer that more data
+    /// can be appended to with feed()
+    pub fn new_fed() -> Self {
+        Lexer{
+            source: CharSource::Fed(FedSource{ buffer: String::new(), cursor: 0 }),
+            data: None,
+            position: Position::start(),
+            last_position: Position::start(),
+            max_string_len: None,
+        }
+    }
+
+    /// Appends more data to a fed lexer. Has no effect on other sources.
+    pub fn feed(&mut self, more: &str) {
+        if let CharSource::Fed(source) = &mut self.source {
+            source.buffer.push_str(more);
+        }
+    }
+
+    /// @return Whether this lexer pulls from an incrementally fed buffer
+    fn is_fed(&self) -> bool {
+        return matches!(self.source, CharSource::Fed(_));
+    }
+
+    /// Captures the current read position so a partially consumed entry can be
+    /// re-lexed after more data was fed
+    fn snapshot(&self) -> LexerSnapshot {
+        let cursor = match &self.source {
+            CharSource::Fed(source) => source.cursor,
+            _ => 0,
+        };
+        LexerSnapshot{
+            cursor,
+            position: self.position,
+            last_position: self.last_position,
+        }
+    }
+
+    /// Rewinds the lexer to a previously captured snapshot. Only meaningful for
+    /// fed sources, whose buffer is retained in full.
+    fn restore(&mut self, snapshot: &LexerSnapshot) {
+        if let CharSource::Fed(source) = &mut self.source {
+            source.cursor = snapshot.cursor;
+        }
+        self.position = snapshot.position;
+        self.last_position = snapshot.last_position;
+    }
+
+    /// Caps the length of string values. Once a string under construction
+    /// exceeds the cap the lexer stops with StringTooLong, so a pathological
+    /// or unterminated string cannot consume memory without bound.
+    pub fn set_max_string_len(&mut self, max: usize) {
+        self.max_string_len = Some(max);
+    }
+
+    /// Consumes the next token from the data stream
+    /// @return The next token, None once the end of data is reached, an error otherwise
+    pub fn next_token(&mut self) -> Result<Option<Token<'data>>, ParseError> {
+        match self.consume_token() {
+            Ok(token) => Ok(Some(token)),
+            Err(ParseError::EndOfData) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// @return The number of bytes of input consumed so far
+    pub fn bytes_consumed(&self) -> usize {
+        return self.position.offset;
+    }
+
+    /// Peeks at the yet-to-be-consumed portion of the input, which is
+    /// invaluable when diagnosing where a malformed document derailed us.
+    /// Only in-memory sources can offer this; a reader source cannot look
+    /// ahead without consuming, so it reports None.
+    /// @return The unconsumed remainder of the data, or None for a reader source
+    pub fn remaining_input(&self) -> Option<&str> {
+        match &self.source {
+            &CharSource::Str(_) => {
+                // The borrowed data is kept around exactly for slicing like this
+                let data = self.data?;
+                return Some(&data[self.position.offset..]);
+            },
+            &CharSource::Fed(ref source) => return Some(&source.buffer[source.cursor..]),
+            &CharSource::Reader(_) => return None,
+            &CharSource::Chars(_) => return None,
+        }
+    }
+
+    /// Consumes the next character of the source while keeping track of the
+    /// line, column and byte offset we are at
+    fn next_character(&mut self) -> Option<char> {
+        let character = self.source.next_character();
+        if let Some(character) = character {
+            self.last_position = self.position;
+            self.position.advance(character);
+        }
+        return character;
+    }
+
+    /// Consumes the four hex digits of a \uXXXX escape sequence
+    /// @return The code unit they encode, an error on malformed hex or end of data
+    fn consume_hex_code_unit(&mut self) -> Result<u32, ParseError> {
+        let mut sequence = String::new();
+        for _ in 0..4 {
+            match self.next_character() {
+                Some(hex_character) => sequence.push(hex_character),
+                None => return Err(ParseError::EndOfData),
+            }
+        }
+        // from_str_radix would also accept a leading '+', which is not valid
+        // JSON; insist on four plain hex digits before converting
+        if !sequence.chars().all(|hex_character| { return hex_character.is_ascii_hexdigit(); }) {
+            return Err(ParseError::InvalidUnicodeEscape(sequence));
+        }
+        match u32::from_str_radix(sequence.as_str(), 16) {
+            Ok(code_unit) => Ok(code_unit),
+            Err(_) => Err(ParseError::InvalidUnicodeEscape(sequence)),
+        }
+    }
+
+    /// Consumes a \uXXXX escape sequence after the introducing "\u" was consumed,
+    /// combining surrogate pairs into a single character where needed
+    /// @return The character the sequence encodes, an error on malformed hex or an unpaired surrogate
+    fn consume_unicode_escape(&mut self) -> Result<char, ParseError> {
+        let code_unit = self.consume_hex_code_unit()?;
+
+        // Outside the surrogate range the code unit is the scalar value itself
+        if code_unit < 0xD800 || code_unit > 0xDFFF {
+            match char::from_u32(code_unit) {
+                Some(character) => return Ok(character),
+                None => return Err(ParseError::InvalidUnicodeEscape(format!("{:04x}", code_unit))),
+            }
+        }
+
+        // A low surrogate without a preceding high surrogate is malformed
+        if code_unit >= 0xDC00 {
+            return Err(ParseError::InvalidUnicodeEscape(format!("{:04x}", code_unit)));
+        }
+
+        // A high surrogate must be followed by a second \uXXXX low surrogate
+        match (self.next_character(), self.next_character()) {
+            (Some('\\'), Some('u')) => {},
+            _ => return Err(ParseError::InvalidUnicodeEscape(format!("{:04x}", code_unit))),
+        }
+        let low_code_unit = self.consume_hex_code_unit()?;
+        if low_code_unit < 0xDC00 || low_code_unit > 0xDFFF {
+            return Err(ParseError::InvalidUnicodeEscape(format!("{:04x}\\u{:04x}", code_unit, low_code_unit)));
+        }
+
+        let combined = 0x10000 + ((code_unit - 0xD800) << 10) + (low_code_unit - 0xDC00);
+        match char::from_u32(combined) {
+            Some(character) => Ok(character),
+            None => Err(ParseError::InvalidUnicodeEscape(format!("{:04x}\\u{:04x}", code_unit, low_code_unit))),
+        }
+    }
+
+    /// Consumes the remaining characters of a bare keyword such as 'true' whose
+    /// first character was already consumed
+    /// @return Ok(()) if the characters matched, an error otherwise
+    fn consume_keyword(&mut self, remainder: &str) -> Result<(), ParseError> {
+        for expected_character in remainder.chars() {
+            match self.next_character() {
+                Some(character) => {
+                    if character != expected_character {
+                        return Err(ParseError::UnrecognisedToken{ character, position: self.last_position });
                     }
-                    self.state = State::Object;
                 },
-
-                (_, token) => {
-                    print!("T!(unexpected token {:?} in state {:?})", token, self.state);
-                }
+                None => return Err(ParseError::EndOfData),
             }
         }
+        return Ok(());
+    }
 
-        return Err(ParseError::EndOfData);
+    /// Continues lexing a number that turned out not to be a plain integer:
+    /// a fraction, an exponent (with optional sign) or a leading minus. The
+    /// digits seen so far arrive as a string; the grammar is enforced here so
+    /// the final parse cannot fail on position mistakes like a stray '+'.
+    /// @return The float token, or an error for malformed number syntax
+    fn consume_float_token(&mut self, mut number_string: String, negative: bool) -> Result<Token<'data>, ParseError> {
+        let mut seen_dot = false;
+        let mut seen_exponent = false;
+        while let Some(number_character) = self.source.peek_character() {
+            match number_character {
+                '0' | '1' | '2' | '3' |  '4' |  '5' |  '6' |  '7' |  '8' |  '9' => {
+                    number_string.push(number_character);
+                    self.next_character();
+                },
+                '.' => {
+                    if seen_dot || seen_exponent {
+                        break; // A second dot belongs to whatever follows this token
+                    }
+                    seen_dot = true;
+                    number_string.push(number_character);
+                    self.next_character();
+                },
+                'e' | 'E' => {
+                    if seen_exponent {
+                        break;
+                    }
+                    seen_exponent = true;
+                    number_string.push(number_character);
+                    self.next_character();
+                    // The exponent may carry a sign, but only right here
+                    if let Some(sign_character) = self.source.peek_character() {
+                        if sign_character == '+' || sign_character == '-' {
+                            number_string.push(sign_character);
+                            self.next_character();
+                        }
+                    }
+                    // At least one digit has to follow the exponent
+                    match self.source.peek_character() {
+                        Some(digit_character) if digit_character.is_ascii_digit() => {},
+                        Some(other_character) => {
+                            return Err(ParseError::UnrecognisedToken{ character: other_character, position: self.position });
+                        },
+                        None => return Err(ParseError::EndOfData),
+                    }
+                },
+                _ => break,
+            }
+        }
+
+        match number_string.parse::<f64>() {
+            Ok(value) => {
+                let value = match negative {
+                    true => -value,
+                    false => value,
+                };
+                return Ok(Token::FloatValue(value));
+            },
+            // The grammar above only admits parseable shapes
+            Err(_) => return Err(ParseError::UnrecognisedToken{ character: 'e', position: self.last_position }),
+        }
+    }
+
+    /// Consumes the next token from our current data stream
+    /// @return A token if the next token could be parsed successfully, an error otherwise (including end of data)
+    fn consume_token(&mut self) -> Result<Token<'data>, ParseError> {
+        while let Some(character) = self.next_character() {
+            match character {
+                '\u{feff}' => {
+                    // Skip a byte order mark; some tools prefix exported files with one
+                    if self.last_position.offset == 0 {
+                        continue;
+                    }
+                    return Err(ParseError::UnrecognisedToken{ character, position: self.last_position });
+                },
+                '[' => {
+                    return Ok(Token::ArrayStart)
+                },
+                ']' => {
+                    return Ok(Token::ArrayEnd)
+                },
+                '{' => {
+                    return Ok(Token::ObjectStart)
+                },
+                '}' => {
+                    return Ok(Token::ObjectEnd)
+                },
+                ',' | ':' => {
+                    // Purposefully skip key identifiers and separators. Since
+                    // commas never reach the state machine, trailing commas as in
+                    // {"a":1,} or [...,] are tolerated by construction.
+                    continue;
+                }
+                ' ' | '\t' | '\n' | '\r' => {
+                    // Whitespace between tokens carries no meaning; a
+                    // pretty-printed document or a trailing newline lexes the
+                    // same as the compact form
+                    continue;
+                }
+                '"' => {
+                    // Parse a string: any character is accepted until next occurence of '"',
+                    // with '\' introducing the standard JSON escape sequences.
+                    // As long as no escape appears and the data lives in memory we only track
+                    // the span and borrow it at the end, avoiding a per-token allocation.
+                    let start_offset = self.position.offset;
+                    let mut owned: Option<String> = match self.data {
+                        Some(_) => None,
+                        None => Some(String::new()),
+                    };
+                    let mut terminated = false;
+                    let mut length: usize = 0;
+                    while let Some(string_character) = self.next_character() {
+                        match string_character {
+                            '"' => {
+                                terminated = true;
+                                break;
+                            },
+                            '\\' => {
+                                // An escape forces us onto the owned path: copy what we
+                                // have so far and push decoded characters from here on
+                                if owned.is_none() {
+                                    let data = self.data.unwrap();
+                                    owned = Some(String::from(&data[start_offset..self.last_position.offset]));
+                                }
+                                let value = owned.as_mut().unwrap();
+                                let escaped_character = match self.next_character() {
+                                    Some(escaped_character) => escaped_character,
+                                    None => return Err(ParseError::EndOfData),
+                                };
+                                match escaped_character {
+                                    '"' => value.push('"'),
+                                    '\\' => value.push('\\'),
+                                    '/' => value.push('/'),
+                                    'n' => value.push('\n'),
+                                    't' => value.push('\t'),
+                                    'r' => value.push('\r'),
+                                    'b' => value.push('\u{0008}'),
+                                    'f' => value.push('\u{000c}'),
+                                    'u' => {
+                                        let unescaped = self.consume_unicode_escape()?;
+                                        owned.as_mut().unwrap().push(unescaped);
+                                    },
+                                    _ => return Err(ParseError::InvalidEscape(escaped_character)),
+                                }
+                            },
+                            _ => {
+                                if let Some(value) = &mut owned {
+                                    value.push(string_character);
+                                }
+                            },
+                        }
+                        // Each decoded character counts towards the cap, a guard
+                        // against a malformed feed streaming an endless string
+                        length += 1;
+                        if let Some(max) = self.max_string_len {
+                            if length > max {
+                                return Err(ParseError::StringTooLong{ limit: max });
+                            }
+                        }
+                    }
+                    if !terminated {
+                        // The input ran out before the closing quote. A fed source is
+                        // merely waiting for more data, so report a plain end of data
+                        // and let the entry-level rewind take over; anything else is
+                        // a truncated body worth flagging with its partial content.
+                        if let CharSource::Fed(_) = self.source {
+                            return Err(ParseError::EndOfData);
+                        }
+                        let partial = match owned {
+                            Some(value) => value,
+                            None => String::from(&self.data.unwrap()[start_offset..self.position.offset]),
+                        };
+                        return Err(ParseError::UnterminatedString(partial));
+                    }
+                    match owned {
+                        Some(value) => return Ok(Token::StringValue(Cow::Owned(value))),
+                        None => {
+                            let data = self.data.unwrap();
+                            return Ok(Token::StringValue(Cow::Borrowed(&data[start_offset..self.last_position.offset])));
+                        },
+                    }
+                },
+                't' => {
+                    // The 'true' keyword
+                    self.consume_keyword("rue")?;
+                    return Ok(Token::BoolValue(true));
+                },
+                'f' => {
+                    // The 'false' keyword
+                    self.consume_keyword("alse")?;
+                    return Ok(Token::BoolValue(false));
+                },
+                'n' => {
+                    // The 'null' keyword
+                    self.consume_keyword("ull")?;
+                    return Ok(Token::Null);
+                },
+                '0' | '1' | '2' | '3' |  '4' |  '5' |  '6' |  '7' |  '8' |  '9' => {
+                    // Parse a number: accumulate the value digit by digit instead of
+                    // building an intermediate String, this is a hot path.
+                    // Important here is to not consume the first non-digit character
+                    let mut number_value = character as u64 - '0' as u64;
+                    while let Some(number_character) = self.source.peek_character() {
+                        match number_character {
+                            '0' | '1' | '2' | '3' |  '4' |  '5' |  '6' |  '7' |  '8' |  '9' => {
+                                let digit = number_character as u64 - '0' as u64;
+                                number_value = match number_value.checked_mul(10).and_then(|value| { return value.checked_add(digit); }) {
+                                    Some(value) => value,
+                                    None => {
+                                        // The number exceeds u64: rebuild the digit string so the
+                                        // error report matches what the data actually said
+                                        let mut number_string = number_value.to_string();
+                                        number_string.push(number_character);
+                                        self.next_character();
+                                        while let Some(digit_character) = self.source.peek_character() {
+                                            if !digit_character.is_ascii_digit() {
+                                                break;
+                                            }
+                                            number_string.push(digit_character);
+                                            self.next_character();
+                                        }
+                                        match number_string.parse::<u64>() {
+                                            Err(error) => return Err(ParseError::ParseIntError{ value: number_string, error }),
+                                            // Cannot be reached: the accumulation above already overflowed
+                                            Ok(value) => return Ok(Token::NumberValue(value)),
+                                        }
+                                    },
+                                };
+                                self.next_character();
+                            },
+                            '.' | 'e' | 'E' => {
+                                // The integer continues as a float: switch to the
+                                // string-based float path from here on
+                                return self.consume_float_token(number_value.to_string(), false);
+                            },
+                            _ => {
+                                return Ok(Token::NumberValue(number_value));
+                            }
+                        }
+                    }
+                    // The input ended right after the digits. A fed source may
+                    // still be waiting for the rest of the number, so report a
+                    // plain end of data and let the entry-level rewind retry;
+                    // otherwise the number is complete and must not be dropped.
+                    if let CharSource::Fed(_) = self.source {
+                        return Err(ParseError::EndOfData);
+                    }
+                    return Ok(Token::NumberValue(number_value));
+                }
+                '-' => {
+                    // A signed number; JSON allows the minus only up front
+                    match self.source.peek_character() {
+                        Some(digit_character) if digit_character.is_ascii_digit() => {
+                            return self.consume_float_token(String::new(), true);
+                        },
+                        _ => {
+                            return Err(ParseError::UnrecognisedToken{ character, position: self.last_position });
+                        },
+                    }
+                }
+                _ => {
+                    return Err(ParseError::UnrecognisedToken{ character, position: self.last_position });
+                },
+            }
+        }
+
+        // The stream ran dry: surface a pending source error over a plain end of data
+        match self.source.take_error() {
+            Some(error) => return Err(error),
+            None => return Err(ParseError::EndOfData),
+        }
+    }
+
+}
+
+// Of course, this is way more complicated than using Serde for example
+// But this also gives us the power of optimizing the entirety of the algorithm
+// Let's define our parser as a struct that borrows data with lifetime 'data
+pub struct Parser<'data>{
+    state: State,
+    lexer: Lexer<'data>,
+    lenient: bool,
+    check_missing_fields: bool,
+    check_duplicate_keys: bool,
+    seen_keys: Vec<String>,
+    symbol_filter: Option<Box<dyn Fn(&str) -> bool>>,
+    max_entries: Option<usize>,
+    parsed_entries: usize,
+    capture_unknown_keys: bool,
+    // Whether the current document is a bare top-level object rather than an array
+    bare_document: bool,
+    // How many arrays we are nested inside; batch endpoints wrap objects in arrays of arrays
+    array_depth: usize,
+    // 1-based index of the array entry currently being parsed, for error reports
+    current_entry_index: usize,
+    // Whether each entry's key order is recorded for auditing
+    record_key_order: bool,
+    // Canonical names for renamed keys, applied before any key matching
+    field_aliases: Option<std::collections::HashMap<String, String>>,
+    // How deep parse_value follows nested structures before bailing out
+    max_value_depth: usize,
+    // Byte offset of the opening brace of the entry parsed most recently
+    entry_start_offset: usize,
+    // Whether a token after the document's closing bracket is an error
+    fail_on_trailing_data: bool,
+    // Whether a document body has been opened, to tell trailing data apart
+    // from a document that has not started yet
+    document_opened: bool,
+    // A one-entry buffer filled by peek_entry and drained by parse_single
+    peeked: Option<ResultEntry>,
+    validate_symbol: bool,
+}
+
+// Note on encodings: since we iterate over chars() the lexer always sees whole unicode
+// scalar values, never individual bytes of a multi-byte UTF-8 sequence. Non-ASCII content
+// inside string values passes through untouched; a stray non-ASCII character outside of a
+// string is reported as a clean UnrecognisedToken with the full character.
+impl<'data> Parser<'data> {
+    /// Create a new Parser that borrows the data given. Accepting &str means
+    /// callers holding a String as well as ones holding a plain slice can use it.
+    pub fn new(data: &'data str) -> Self {
+        Parser{
+            state: State::Init,
+            lexer: Lexer::new(data),
+            lenient: false,
+            check_missing_fields: false,
+            check_duplicate_keys: false,
+            seen_keys: Vec::new(),
+            symbol_filter: None,
+            max_entries: None,
+            parsed_entries: 0,
+            capture_unknown_keys: false,
+            bare_document: false,
+            array_depth: 0,
+            current_entry_index: 0,
+            record_key_order: false,
+            field_aliases: None,
+            max_value_depth: 128,
+            entry_start_offset: 0,
+            fail_on_trailing_data: false,
+            document_opened: false,
+            peeked: None,
+            validate_symbol: false,
+        }
+    }
+
+    /// Create a new Parser that pulls its data lazily from the reader given,
+    /// so entries can be produced before the whole body has arrived
+    pub fn from_reader<R: std::io::Read + 'data>(reader: R) -> Self {
+        Parser{
+            state: State::Init,
+            lexer: Lexer::from_reader(reader),
+            lenient: false,
+            check_missing_fields: false,
+            check_duplicate_keys: false,
+            seen_keys: Vec::new(),
+            symbol_filter: None,
+            max_entries: None,
+            parsed_entries: 0,
+            capture_unknown_keys: false,
+            bare_document: false,
+            array_depth: 0,
+            current_entry_index: 0,
+            record_key_order: false,
+            field_aliases: None,
+            max_value_depth: 128,
+            entry_start_offset: 0,
+            fail_on_trailing_data: false,
+            document_opened: false,
+            peeked: None,
+            validate_symbol: false,
+        }
+    }
+
+    /// Create a new Parser over an arbitrary stream of already-decoded
+    /// characters, e.g. a decompressor yielding chars
+    pub fn from_chars(chars: impl Iterator<Item = char> + 'data) -> Self {
+        Parser{
+            state: State::Init,
+            lexer: Lexer::from_chars(chars),
+            lenient: false,
+            check_missing_fields: false,
+            check_duplicate_keys: false,
+            seen_keys: Vec::new(),
+            symbol_filter: None,
+            max_entries: None,
+            parsed_entries: 0,
+            capture_unknown_keys: false,
+            bare_document: false,
+            array_depth: 0,
+            current_entry_index: 0,
+            record_key_order: false,
+            field_aliases: None,
+            max_value_depth: 128,
+            entry_start_offset: 0,
+            fail_on_trailing_data: false,
+            document_opened: false,
+            peeked: None,
+            validate_symbol: false,
+        }
+    }
+
+    /// Create a new Parser directly over a byte slice, e.g. a network buffer,
+    /// validating once that the bytes are valid UTF-8
+    /// @return The parser, or an InvalidUtf8 error describing the offending bytes
+    pub fn from_bytes(data: &'data [u8]) -> Result<Self, ParseError> {
+        match std::str::from_utf8(data) {
+            Ok(data_as_str) => Ok(Self::new(data_as_str)),
+            Err(error) => Err(ParseError::InvalidUtf8(error)),
+        }
+    }
+
+    /// Create a new Parser over an initially empty buffer. Data is appended
+    /// incrementally with feed(), letting chunked transfers be parsed without
+    /// buffering the whole body first.
+    pub fn new_fed() -> Self {
+        Parser{
+            state: State::Init,
+            lexer: Lexer::new_fed(),
+            lenient: false,
+            check_missing_fields: false,
+            check_duplicate_keys: false,
+            seen_keys: Vec::new(),
+            symbol_filter: None,
+            max_entries: None,
+            parsed_entries: 0,
+            capture_unknown_keys: false,
+            bare_document: false,
+            array_depth: 0,
+            current_entry_index: 0,
+            record_key_order: false,
+            field_aliases: None,
+            max_value_depth: 128,
+            entry_start_offset: 0,
+            fail_on_trailing_data: false,
+            document_opened: false,
+            peeked: None,
+            validate_symbol: false,
+        }
+    }
+
+    /// Appends more data to a parser created with new_fed()
+    pub fn feed(&mut self, more: &str) {
+        self.lexer.feed(more);
+    }
+
+    /// Re-initializes the parser over new data, keeping its configuration.
+    /// Useful for polling loops and benchmarks that re-parse without paying
+    /// for a fresh Parser each round.
+    pub fn reset(&mut self, data: &'data str) {
+        self.lexer = Lexer::new(data);
+        self.state = State::Init;
+        self.seen_keys.clear();
+        self.parsed_entries = 0;
+        self.bare_document = false;
+        self.array_depth = 0;
+        self.current_entry_index = 0;
+        self.peeked = None;
+        self.document_opened = false;
+    }
+
+    /// Install a predicate on the symbol field. Entries whose symbol is rejected
+    /// are skipped by parse_single: as soon as the symbol key is seen the rest of
+    /// the object is consumed without parsing its values. Should the symbol not
+    /// appear first the already-parsed fields are simply discarded at the closing
+    /// brace, so filtering is correct either way, just cheapest when it is first.
+    pub fn set_symbol_filter(&mut self, predicate: impl Fn(&str) -> bool + 'static) {
+        self.symbol_filter = Some(Box::new(predicate));
+    }
+
+    /// Caps the number of entries this parser will produce. Once the cap is hit,
+    /// further parse calls return EntryLimitReached, guarding memory usage when
+    /// parsing a response from an untrusted endpoint.
+    pub fn set_max_entries(&mut self, max: usize) {
+        self.max_entries = Some(max);
+    }
+
+    /// Reports how many bytes of the input were consumed so far. Since the
+    /// position advances by each character's UTF-8 length this is an exact byte
+    /// offset into the data, usable as a checkpoint for resuming after a restart.
+    /// @return The number of bytes consumed
+    pub fn bytes_consumed(&self) -> usize {
+        return self.lexer.bytes_consumed();
+    }
+
+    /// Peeks at the yet-to-be-consumed portion of the data, handy for
+    /// inspecting the surrounding context when a parse goes wrong.
+    /// @return The unconsumed remainder of the data, or None for a reader source
+    pub fn remaining_input(&self) -> Option<&str> {
+        return self.lexer.remaining_input();
+    }
+
+    /// Toggle recording of the order keys appear in per object, exposed through
+    /// ResultEntry::key_order for round-tripping and auditing
+    pub fn set_record_key_order(&mut self, record_key_order: bool) {
+        self.record_key_order = record_key_order;
+    }
+
+    /// Caps how deep parse_value follows nested structures. The default of 128
+    /// is plenty for real responses; the cap exists so an adversarially deep
+    /// document is a clean MaxDepthExceeded instead of unbounded memory growth.
+    pub fn set_max_depth(&mut self, max_value_depth: usize) {
+        self.max_value_depth = max_value_depth;
+    }
+
+    /// Caps the length of string values, delegating to the lexer. See
+    /// Lexer::set_max_string_len.
+    pub fn set_max_string_len(&mut self, max: usize) {
+        self.lexer.set_max_string_len(max);
+    }
+
+    /// Installs canonical names for renamed JSON keys. An incoming key is
+    /// looked up in the alias map before any matching, so one parser can cover
+    /// endpoint variants that call the same concept e.g. "vol" and "volume".
+    pub fn set_field_aliases(&mut self, aliases: std::collections::HashMap<String, String>) {
+        self.field_aliases = Some(aliases);
+    }
+
+    /// Maps a key through the alias table if one is installed
+    /// @return The canonical key name under which the value is stored
+    fn normalize_key(&self, key: String) -> String {
+        let aliases = match &self.field_aliases {
+            None => return key,
+            Some(aliases) => aliases,
+        };
+        match aliases.get(&key) {
+            Some(canonical) => return canonical.clone(),
+            None => return key,
+        }
+    }
+
+    /// Toggle strict handling of data following the document. When enabled,
+    /// any token after the top-level closing bracket is a TrailingData error,
+    /// catching concatenated or corrupted responses instead of ignoring them.
+    pub fn set_fail_on_trailing_data(&mut self, fail_on_trailing_data: bool) {
+        self.fail_on_trailing_data = fail_on_trailing_data;
+    }
+
+    /// Toggle validation of the symbol field. When enabled, an object whose
+    /// symbol ends up empty is reported as an EmptySymbol error, catching
+    /// malformed feeds early instead of passing indistinguishable defaults on.
+    pub fn set_validate_symbol(&mut self, validate_symbol: bool) {
+        self.validate_symbol = validate_symbol;
+    }
+
+    /// Toggle capturing of unknown keys. When enabled, keys the entry type does
+    /// not recognise are handed to its set_extra hook (a side map on ResultEntry)
+    /// instead of erroring, so new endpoint fields remain inspectable.
+    pub fn set_capture_unknown_keys(&mut self, capture_unknown_keys: bool) {
+        self.capture_unknown_keys = capture_unknown_keys;
+    }
+
+    /// Toggle lenient mode. When enabled, keys the entry type does not recognise
+    /// are silently ignored instead of aborting the parse, keeping the parser
+    /// forward compatible when the endpoint grows new fields. Strict is the default.
+    pub fn set_lenient(&mut self, lenient: bool) {
+        self.lenient = lenient;
+    }
+
+    /// Toggle checking for missing required fields. When enabled, an object that
+    /// closes without having provided every key in required_keys() is reported as
+    /// a MissingFields error instead of silently keeping default values.
+    pub fn set_check_missing_fields(&mut self, check_missing_fields: bool) {
+        self.check_missing_fields = check_missing_fields;
+    }
+
+    /// Toggle duplicate key detection. When enabled, a key appearing twice within
+    /// one object is reported as a DuplicateKey error instead of silently letting
+    /// the later value win. Useful for catching upstream serialization bugs.
+    pub fn set_check_duplicate_keys(&mut self, check_duplicate_keys: bool) {
+        self.check_duplicate_keys = check_duplicate_keys;
+    }
+
+    /// Records a key of the object currently being built, reporting a duplicate
+    /// if duplicate checking is enabled and the key was already seen
+    /// @return Ok(()) if the key is new or no tracking is active, DuplicateKey otherwise
+    fn record_seen_key(&mut self, key: &String) -> Result<(), ParseError> {
+        if !self.check_missing_fields && !self.check_duplicate_keys {
+            return Ok(());
+        }
+        if self.check_duplicate_keys && self.seen_keys.contains(key) {
+            return Err(ParseError::DuplicateKey{ key: key.clone() });
+        }
+        self.seen_keys.push(key.clone());
+        return Ok(());
+    }
+
+    /// Routes the result of a setter call through unknown key capturing first and
+    /// lenient filtering second
+    /// @return The result given, with unrecognised key errors absorbed accordingly
+    fn absorb_set_result<T: FromJsonObject>(entry: &mut T, capture: bool, lenient: bool, result: Result<(), ParseError>) -> Result<(), ParseError> {
+        if capture {
+            match result {
+                Err(ParseError::UnrecognisedKeyStringValuePair{ key, value }) => {
+                    entry.set_extra(key.as_str(), value);
+                    return Ok(());
+                },
+                Err(ParseError::UnrecognisedKeyNumberValuePair{ key, value }) => {
+                    entry.set_extra(key.as_str(), value.to_string());
+                    return Ok(());
+                },
+                Err(ParseError::UnrecognisedKeyBoolValuePair{ key, value }) => {
+                    entry.set_extra(key.as_str(), value.to_string());
+                    return Ok(());
+                },
+                other => return Self::filter_set_result(lenient, other),
+            }
+        }
+        return Self::filter_set_result(lenient, result);
+    }
+
+    /// Compares the keys seen in the just-closed object against the required ones
+    /// @return Ok(()) if nothing is missing or checking is disabled, MissingFields otherwise
+    fn check_seen_keys<T: FromJsonObject>(&self) -> Result<(), ParseError> {
+        if !self.check_missing_fields {
+            return Ok(());
+        }
+        let missing: Vec<String> = T::required_keys().iter()
+            .filter(|required| { return !self.seen_keys.iter().any(|seen| { return seen == *required; }); })
+            .map(|required| { return String::from(*required); })
+            .collect();
+        if missing.is_empty() {
+            return Ok(());
+        }
+        return Err(ParseError::MissingFields{ missing });
+    }
+
+    /// Annotates an error with the entry index it occurred in
+    /// @return The error wrapped into AtEntry
+    fn at_entry(&self, error: ParseError) -> ParseError {
+        return ParseError::AtEntry{ index: self.current_entry_index, error: Box::new(error) };
+    }
+
+    /// Filters the result of a set_string/set_number/set_bool call according to
+    /// the lenient flag: unrecognised key errors are swallowed in lenient mode
+    /// @return The result given, with unrecognised key errors removed if lenient
+    fn filter_set_result(lenient: bool, result: Result<(), ParseError>) -> Result<(), ParseError> {
+        if !lenient {
+            return result;
+        }
+        match result {
+            Err(ParseError::UnrecognisedKeyStringValuePair{..})
+            | Err(ParseError::UnrecognisedKeyNumberValuePair{..})
+            | Err(ParseError::UnrecognisedKeyBoolValuePair{..}) => Ok(()),
+            other => other,
+        }
+    }
+
+    /// Consumes tokens until a nested structure whose opening bracket was already
+    /// consumed is balanced again. Values inside the structure are discarded.
+    /// @return Ok(()) once the structure is balanced, an error otherwise
+    fn skip_nested_value(&mut self) -> Result<(), ParseError> {
+        let mut depth: usize = 1;
+        while depth > 0 {
+            let token = match self.lexer.consume_token() {
+                Err(error) => return Err(error),
+                Ok(token) => token,
+            };
+            match token {
+                Token::ObjectStart | Token::ArrayStart => depth += 1,
+                Token::ObjectEnd | Token::ArrayEnd => depth -= 1,
+                _ => {},
+            }
+        }
+        return Ok(());
+    }
+
+    /// Consumes the next token for the entry machines. After a finished
+    /// document in strict mode, a lexing error is reported as TrailingData:
+    /// the tail is trailing data whether or not it happens to lex.
+    /// @return The token, or an error as from the lexer
+    fn consume_entry_token(&mut self) -> Result<Token<'data>, ParseError> {
+        match self.lexer.consume_token() {
+            Err(ParseError::UnrecognisedToken{ character, position }) => {
+                if self.fail_on_trailing_data && self.document_opened && matches!(self.state, State::Init) {
+                    return Err(ParseError::TrailingData);
+                }
+                return Err(ParseError::UnrecognisedToken{ character, position });
+            },
+            other => return other,
+        }
+    }
+
+    /// Parses until the first ResultEntry was found
+    /// @return ResultEntry if there is data left, an error otherwise (including end of data)
+    pub fn parse_single(&mut self) -> Result<ResultEntry, ParseError> {
+        // A previously peeked entry is handed out before touching the input
+        // again; it was already counted towards the entry limit when parsed
+        if let Some(entry) = self.peeked.take() {
+            return Ok(entry);
+        }
+        // ResultEntry implements FromJsonObject like any other entry type, so
+        // the generic machine is the single implementation of the parse loop
+        return self.parse_single_into::<ResultEntry>();
+    }
+
+    /// As parse_single, but tagging the entry with the byte offset its opening
+    /// brace sits at in the source, for building an index that allows a specific
+    /// record to be re-parsed later without touching the rest of the document.
+    /// @return The starting offset and the entry, or an error as for parse_single
+    pub fn parse_single_with_offset(&mut self) -> Result<(usize, ResultEntry), ParseError> {
+        let entry = self.parse_single()?;
+        return Ok((self.entry_start_offset, entry));
+    }
+
+    /// Advances past the next object of the array without building an entry,
+    /// the cheap way to discard a record that would be filtered out anyway:
+    /// no values are interpreted and nothing is allocated.
+    /// @return Ok(true) once an entry was skipped, Ok(false) at the end of data
+    pub fn skip_entry(&mut self) -> Result<bool, ParseError> {
+        // A peeked entry is the next entry; dropping the buffer skips it
+        // without touching the input
+        if self.peeked.take().is_some() {
+            return Ok(true);
+        }
+        loop {
+            let token = match self.lexer.consume_token() {
+                Err(ParseError::EndOfData) => return Ok(false),
+                Err(error) => return Err(error),
+                Ok(token) => token,
+            };
+
+            match (&self.state, token) {
+                (&State::Init, Token::ArrayStart) => {
+                    self.state = State::Array;
+                    self.array_depth = 1;
+                },
+                (&State::Array, Token::ArrayStart) => {
+                    self.array_depth += 1;
+                },
+                (&State::Array, Token::ArrayEnd) => {
+                    self.array_depth -= 1;
+                    if self.array_depth == 0 {
+                        self.state = State::Init;
+                    }
+                },
+                (&State::Init, Token::ObjectStart) | (&State::Array, Token::ObjectStart) => {
+                    // The object body is discarded wholesale; the state never
+                    // enters Object, so the next parse continues cleanly
+                    self.current_entry_index += 1;
+                    self.skip_nested_value()?;
+                    return Ok(true);
+                },
+                (_, token) => {
+                    return Err(ParseError::UnexpectedToken{
+                        token_description: format!("{:?}", token),
+                        state_description: format!("{:?}", self.state),
+                    });
+                },
+            }
+        }
+    }
+
+    /// Parses until the next JSON object was completed, filling a caller-provided
+    /// type through the FromJsonObject trait. This is the one parse loop shared
+    /// by every owned entry type: parse_single runs it with ResultEntry, so the
+    /// symbol filter, key-order recording and the fed-source rewind behave the
+    /// same no matter which entry type is asked for.
+    /// @return The filled struct if there is data left, an error otherwise (including end of data)
+    pub fn parse_single_into<T: FromJsonObject>(&mut self) -> Result<T, ParseError> {
+        // A peeked ResultEntry cannot be handed out as an arbitrary T; insist
+        // the buffer is drained through parse_single rather than losing it
+        if self.peeked.is_some() {
+            return Err(ParseError::PendingPeek);
+        }
+        if let Some(max) = self.max_entries {
+            if self.parsed_entries >= max {
+                return Err(ParseError::EntryLimitReached);
+            }
+        }
+        // For a fed source, remember where this entry started: if the buffer runs
+        // dry mid-entry we rewind and report NeedMoreData instead of failing.
+        // The entry under construction is local and simply dropped on a rewind.
+        let snapshot = match self.lexer.is_fed() {
+            true => Some((self.lexer.snapshot(), self.state.clone(), self.array_depth, self.seen_keys.clone(),
+                self.current_entry_index, self.bare_document, self.document_opened)),
+            false => None,
+        };
+        let mut entry = T::default();
+        loop {
+            let token = match self.consume_entry_token() {
+                Err(ParseError::EndOfData) => break,
+                Err(error) => return Err(error),
+                Ok(token) => token,
+            };
+
+            // Note whether a document body is underway, so that in strict mode
+            // a token arriving after the document closed flags as trailing data
+            match &self.state {
+                &State::Init => {
+                    if self.fail_on_trailing_data && self.document_opened {
+                        return Err(ParseError::TrailingData);
+                    }
+                },
+                _ => self.document_opened = true,
+            }
+
+            match (&self.state, token) {
+                (&State::Init, Token::ArrayStart) => {
+                    self.state = State::Array;
+                    self.array_depth = 1;
+                },
+                (&State::Array, Token::ArrayStart) => {
+                    // A nested array of objects: flatten it by just going deeper
+                    self.array_depth += 1;
+                },
+                (&State::Init, Token::ObjectStart) => {
+                    // A bare object document: its closing brace ends the document
+                    self.bare_document = true;
+                    self.state = State::Object;
+                    self.seen_keys.clear();
+                    self.entry_start_offset = self.lexer.last_position.offset;
+                },
+
+                (&State::Array, Token::ObjectStart) => {
+                    self.state = State::Object;
+                    self.seen_keys.clear();
+                    self.current_entry_index += 1;
+                    self.entry_start_offset = self.lexer.last_position.offset;
+                },
+                (&State::Array, Token::ArrayEnd) => {
+                    self.array_depth -= 1;
+                    if self.array_depth == 0 {
+                        self.state = State::Init;
+                    }
+                },
+
+                (&State::Object, Token::StringValue(key)) => {
+                    let key = self.normalize_key(key.into_owned());
+                    if let Err(error) = self.record_seen_key(&key) {
+                        // A duplicate key is an entry-level problem; report it
+                        // with the entry index like the value errors
+                        return Err(self.at_entry(error));
+                    }
+                    if self.record_key_order {
+                        entry.record_key(&key);
+                    }
+                    self.state = State::Key(key);
+                },
+                (&State::Object, Token::ObjectEnd) => {
+                    self.state = match self.bare_document {
+                        true => State::Init,
+                        false => State::Array,
+                    };
+                    if let Err(error) = self.check_seen_keys::<T>() {
+                        return Err(self.at_entry(error));
+                    }
+                    // Entry types without a symbol pass validation and the
+                    // filter untouched
+                    let discard = match entry.symbol() {
+                        Some(symbol) => {
+                            if self.validate_symbol && symbol.is_empty() {
+                                return Err(self.at_entry(ParseError::EmptySymbol));
+                            }
+                            match &self.symbol_filter {
+                                Some(predicate) => !predicate(symbol),
+                                None => false,
+                            }
+                        },
+                        None => false,
+                    };
+                    if discard {
+                        // A rejected entry is discarded; move on to the next object
+                        entry = T::default();
+                        continue;
+                    }
+                    // The finished entry moves out; no clone, no field churn
+                    self.parsed_entries += 1;
+                    return Ok(entry);
+                },
+
+                (&State::Key(ref key), Token::StringValue(value)) => {
+                    if key == "symbol" {
+                        if let Some(predicate) = &self.symbol_filter {
+                            if !predicate(&value) {
+                                // Rejected early: consume the rest of the object without
+                                // parsing any further values
+                                self.skip_nested_value()?;
+                                self.state = match self.bare_document {
+                                    true => State::Init,
+                                    false => State::Array,
+                                };
+                                entry = T::default();
+                                continue;
+                            }
+                        }
+                    }
+                    let result = entry.set_string(key, value.into_owned());
+                    if let Err(error) = Self::absorb_set_result(&mut entry, self.capture_unknown_keys, self.lenient, result) {
+                        return Err(self.at_entry(error));
+                    }
+                    self.state = State::Object;
+                },
+
+                (&State::Key(_), Token::ObjectStart) | (&State::Key(_), Token::ArrayStart) => {
+                    // An unknown nested structure under a key is skipped so the
+                    // outer entry still parses
+                    self.skip_nested_value()?;
+                    self.state = State::Object;
+                },
+
+                (&State::Key(_), Token::Null) => {
+                    // A null value leaves the corresponding field at its default
+                    self.state = State::Object;
+                },
+
+                (&State::Key(ref key), Token::BoolValue(value)) => {
+                    let result = entry.set_bool(key, value);
+                    if let Err(error) = Self::absorb_set_result(&mut entry, self.capture_unknown_keys, self.lenient, result) {
+                        return Err(self.at_entry(error));
+                    }
+                    self.state = State::Object;
+                },
+
+                (&State::Key(ref key), Token::NumberValue(value)) => {
+                    let result = entry.set_number(key, value);
+                    if let Err(error) = Self::absorb_set_result(&mut entry, self.capture_unknown_keys, self.lenient, result) {
+                        return Err(self.at_entry(error));
+                    }
+                    self.state = State::Object;
+                },
+
+                (_, token) => {
+                    // A structurally broken document must surface an error rather
+                    // than being papered over
+                    return Err(ParseError::UnexpectedToken{
+                        token_description: format!("{:?}", token),
+                        state_description: format!("{:?}", self.state),
+                    });
+                }
+            }
+        }
+
+        // A fed source that ran dry below the top level is merely waiting for
+        // more data: rewind to the entry start so the retry re-lexes cleanly
+        if let Some((lexer_snapshot, state, array_depth, seen_keys, current_entry_index, bare_document, document_opened)) = snapshot {
+            if !matches!(self.state, State::Init) {
+                self.lexer.restore(&lexer_snapshot);
+                self.state = state;
+                self.array_depth = array_depth;
+                self.seen_keys = seen_keys;
+                // The entry counter and document flags were touched while the
+                // dropped entry was underway; roll them back too, or every
+                // rewind would inflate the AtEntry indices by one
+                self.current_entry_index = current_entry_index;
+                self.bare_document = bare_document;
+                self.document_opened = document_opened;
+                return Err(ParseError::NeedMoreData);
+            }
+        }
+
+        return Err(ParseError::EndOfData);
+    }
+
+    /// Routes a string value into the borrowing RawEntry. Float fields are parsed
+    /// straight from the span so no allocation happens on the happy path.
+    /// @return Ok(()) if the key was recognised, an error otherwise
+    fn set_raw_string(entry: &mut RawEntry<'data>, key: &str, value: Cow<'data, str>) -> Result<(), ParseError> {
+        if key == "symbol" {
+            entry.symbol = value;
+            return Ok(());
+        }
+        let field = match key {
+            "priceChange" => &mut entry.price_change,
+            "priceChangePercent" => &mut entry.price_change_percent,
+            "lastPrice" => &mut entry.last_price,
+            "lastQty" => &mut entry.last_qty,
+            "open" => &mut entry.open,
+            "high" => &mut entry.high,
+            "low" => &mut entry.low,
+            "volume" => &mut entry.volume,
+            "amount" => &mut entry.amount,
+            "bidPrice" => &mut entry.bid_price,
+            "askPrice" => &mut entry.ask_price,
+            "strikePrice" => &mut entry.strike_price,
+            "exercisePrice" => &mut entry.exercise_price,
+            _ => return Err(ParseError::UnrecognisedKeyStringValuePair{ key: String::from(key), value: value.into_owned() }),
+        };
+        match value.parse::<f64>() {
+            Ok(parsed) => *field = parsed,
+            Err(error) => return Err(ParseError::ParseFloatError{ key: String::from(key), value: value.into_owned(), error }),
+        }
+        return Ok(());
+    }
+
+    /// Routes a number value into the borrowing RawEntry
+    /// @return Ok(()) if the key was recognised, an error otherwise
+    fn set_raw_number(entry: &mut RawEntry<'data>, key: &str, value: u64) -> Result<(), ParseError> {
+        match key {
+            "openTime" => entry.open_time = value,
+            "closeTime" => entry.close_time = value,
+            "firstTradeId" => entry.first_trade_id = value,
+            "tradeCount" => entry.trade_count = value,
+            _ => return Err(ParseError::UnrecognisedKeyNumberValuePair{ key: String::from(key), value }),
+        }
+        return Ok(());
+    }
+
+    /// Parses until the next JSON object was completed, borrowing string data
+    /// from the input instead of allocating owned copies. The borrowed lifetime
+    /// keeps this path out of the FromJsonObject machine, but it honours the
+    /// same parser settings; only key-order recording and unknown-key capture
+    /// do not apply, as RawEntry stores neither.
+    /// @return The borrowed entry if there is data left, an error otherwise (including end of data)
+    pub fn parse_single_raw(&mut self) -> Result<RawEntry<'data>, ParseError> {
+        // A peeked ResultEntry owns its data and cannot be handed out as a
+        // borrowed entry; it has to be drained through parse_single
+        if self.peeked.is_some() {
+            return Err(ParseError::PendingPeek);
+        }
+        if let Some(max) = self.max_entries {
+            if self.parsed_entries >= max {
+                return Err(ParseError::EntryLimitReached);
+            }
+        }
+        // For a fed source, remember where this entry started: if the buffer runs
+        // dry mid-entry we rewind and report NeedMoreData instead of failing
+        let snapshot = match self.lexer.is_fed() {
+            true => Some((self.lexer.snapshot(), self.state.clone(), self.array_depth, self.seen_keys.clone(),
+                self.current_entry_index, self.bare_document, self.document_opened)),
+            false => None,
+        };
+        let mut entry = RawEntry::new();
+        loop {
+            let token = match self.consume_entry_token() {
+                Err(ParseError::EndOfData) => break,
+                Err(error) => return Err(error),
+                Ok(token) => token,
+            };
+
+            // Note whether a document body is underway, so that in strict mode
+            // a token arriving after the document closed flags as trailing data
+            match &self.state {
+                &State::Init => {
+                    if self.fail_on_trailing_data && self.document_opened {
+                        return Err(ParseError::TrailingData);
+                    }
+                },
+                _ => self.document_opened = true,
+            }
+
+            match (&self.state, token) {
+                (&State::Init, Token::ArrayStart) => {
+                    self.state = State::Array;
+                    self.array_depth = 1;
+                },
+                (&State::Array, Token::ArrayStart) => {
+                    // A nested array of objects: flatten it by just going deeper
+                    self.array_depth += 1;
+                },
+                (&State::Init, Token::ObjectStart) => {
+                    // A bare object document: its closing brace ends the document
+                    self.bare_document = true;
+                    self.state = State::Object;
+                    self.seen_keys.clear();
+                    self.entry_start_offset = self.lexer.last_position.offset;
+                },
+
+                (&State::Array, Token::ObjectStart) => {
+                    self.state = State::Object;
+                    self.seen_keys.clear();
+                    self.current_entry_index += 1;
+                    self.entry_start_offset = self.lexer.last_position.offset;
+                },
+                (&State::Array, Token::ArrayEnd) => {
+                    self.array_depth -= 1;
+                    if self.array_depth == 0 {
+                        self.state = State::Init;
+                    }
+                },
+
+                (&State::Object, Token::StringValue(key)) => {
+                    let key = self.normalize_key(key.into_owned());
+                    if let Err(error) = self.record_seen_key(&key) {
+                        // A duplicate key is an entry-level problem; report it
+                        // with the entry index like the value errors
+                        return Err(self.at_entry(error));
+                    }
+                    self.state = State::Key(key);
+                },
+                (&State::Object, Token::ObjectEnd) => {
+                    self.state = match self.bare_document {
+                        true => State::Init,
+                        false => State::Array,
+                    };
+                    // RawEntry mirrors the ResultEntry schema, so the required
+                    // keys of ResultEntry apply to it unchanged
+                    if let Err(error) = self.check_seen_keys::<ResultEntry>() {
+                        return Err(self.at_entry(error));
+                    }
+                    if self.validate_symbol && entry.symbol.is_empty() {
+                        return Err(self.at_entry(ParseError::EmptySymbol));
+                    }
+                    if let Some(predicate) = &self.symbol_filter {
+                        if !predicate(&entry.symbol) {
+                            // A rejected entry is discarded; move on to the next object
+                            entry = RawEntry::new();
+                            continue;
+                        }
+                    }
+                    self.parsed_entries += 1;
+                    return Ok(entry);
+                },
+
+                (&State::Key(ref key), Token::StringValue(value)) => {
+                    if key == "symbol" {
+                        if let Some(predicate) = &self.symbol_filter {
+                            if !predicate(&value) {
+                                // Rejected early: consume the rest of the object without
+                                // parsing any further values
+                                self.skip_nested_value()?;
+                                self.state = match self.bare_document {
+                                    true => State::Init,
+                                    false => State::Array,
+                                };
+                                entry = RawEntry::new();
+                                continue;
+                            }
+                        }
+                    }
+                    if let Err(error) = Self::filter_set_result(self.lenient, Self::set_raw_string(&mut entry, key, value)) {
+                        return Err(self.at_entry(error));
+                    }
+                    self.state = State::Object;
+                },
+
+                (&State::Key(_), Token::ObjectStart) | (&State::Key(_), Token::ArrayStart) => {
+                    self.skip_nested_value()?;
+                    self.state = State::Object;
+                },
+
+                (&State::Key(_), Token::Null) => {
+                    self.state = State::Object;
+                },
+
+                (&State::Key(ref key), Token::BoolValue(value)) => {
+                    Self::filter_set_result(self.lenient, Err(ParseError::UnrecognisedKeyBoolValuePair{ key: key.clone(), value }))?;
+                    self.state = State::Object;
+                },
+
+                (&State::Key(ref key), Token::NumberValue(value)) => {
+                    if let Err(error) = Self::filter_set_result(self.lenient, Self::set_raw_number(&mut entry, key, value)) {
+                        return Err(self.at_entry(error));
+                    }
+                    self.state = State::Object;
+                },
+
+                (_, token) => {
+                    // A structurally broken document must surface an error rather
+                    // than being papered over
+                    return Err(ParseError::UnexpectedToken{
+                        token_description: format!("{:?}", token),
+                        state_description: format!("{:?}", self.state),
+                    });
+                }
+            }
+        }
+
+        // A fed source that ran dry below the top level is merely waiting for
+        // more data: rewind to the entry start so the retry re-lexes cleanly
+        if let Some((lexer_snapshot, state, array_depth, seen_keys, current_entry_index, bare_document, document_opened)) = snapshot {
+            if !matches!(self.state, State::Init) {
+                self.lexer.restore(&lexer_snapshot);
+                self.state = state;
+                self.array_depth = array_depth;
+                self.seen_keys = seen_keys;
+                // The entry counter and document flags were touched while the
+                // dropped entry was underway; roll them back too, or every
+                // rewind would inflate the AtEntry indices by one
+                self.current_entry_index = current_entry_index;
+                self.bare_document = bare_document;
+                self.document_opened = document_opened;
+                return Err(ParseError::NeedMoreData);
+            }
+        }
+
+        return Err(ParseError::EndOfData);
+    }
+
+    /// Parses the next complete JSON value of any shape into a generic tree,
+    /// complementing the schema-specific entry path for exploratory use.
+    /// Nesting is tracked on an explicit frame stack rather than by recursion,
+    /// so a maliciously deep document hits the depth limit, never the thread's
+    /// stack.
+    /// @return The value parsed, or an error (including end of data)
+    pub fn parse_value(&mut self) -> Result<JsonValue, ParseError> {
+        let mut stack: Vec<ValueFrame> = Vec::new();
+        loop {
+            // An object frame still waiting for a key accepts only a key
+            // string or the closing brace
+            let expects_key = match stack.last() {
+                Some(&ValueFrame::Object(_, None)) => true,
+                _ => false,
+            };
+
+            let token = self.lexer.consume_token()?;
+            let completed = match expects_key {
+                true => match token {
+                    Token::ObjectEnd => {
+                        match stack.pop() {
+                            Some(ValueFrame::Object(members, _)) => JsonValue::Object(members),
+                            _ => unreachable!("expects_key guaranteed an object frame on top"),
+                        }
+                    },
+                    Token::StringValue(key) => {
+                        if let Some(&mut ValueFrame::Object(_, ref mut pending)) = stack.last_mut() {
+                            *pending = Some(key.into_owned());
+                        }
+                        continue;
+                    },
+                    // Anything but a key or the closing brace is out of place here
+                    _ => return Err(ParseError::UnrecognisedToken{ character: '{', position: self.lexer.last_position }),
+                },
+                false => match token {
+                    Token::StringValue(value) => JsonValue::String(value.into_owned()),
+                    Token::NumberValue(value) => JsonValue::Number(value),
+                    Token::FloatValue(value) => JsonValue::Float(value),
+                    Token::BoolValue(value) => JsonValue::Bool(value),
+                    Token::Null => JsonValue::Null,
+
+                    Token::ArrayStart => {
+                        if stack.len() >= self.max_value_depth {
+                            return Err(ParseError::MaxDepthExceeded{ limit: self.max_value_depth });
+                        }
+                        stack.push(ValueFrame::Array(Vec::new()));
+                        continue;
+                    },
+                    Token::ObjectStart => {
+                        if stack.len() >= self.max_value_depth {
+                            return Err(ParseError::MaxDepthExceeded{ limit: self.max_value_depth });
+                        }
+                        stack.push(ValueFrame::Object(Vec::new(), None));
+                        continue;
+                    },
+
+                    Token::ArrayEnd => {
+                        match stack.last() {
+                            Some(&ValueFrame::Array(_)) => {
+                                match stack.pop() {
+                                    Some(ValueFrame::Array(values)) => JsonValue::Array(values),
+                                    _ => unreachable!("the frame on top was just matched as an array"),
+                                }
+                            },
+                            // A stray closing bracket has no value to offer
+                            _ => return Err(ParseError::UnrecognisedToken{ character: ']', position: self.lexer.last_position }),
+                        }
+                    },
+                    Token::ObjectEnd => return Err(ParseError::UnrecognisedToken{ character: '}', position: self.lexer.last_position }),
+                },
+            };
+
+            // Hand the completed value to the enclosing frame, or out to the caller
+            match stack.last_mut() {
+                None => return Ok(completed),
+                Some(&mut ValueFrame::Array(ref mut values)) => values.push(completed),
+                Some(&mut ValueFrame::Object(ref mut members, ref mut pending)) => {
+                    match pending.take() {
+                        Some(key) => members.push((key, completed)),
+                        None => unreachable!("a value completed inside an object without a pending key"),
+                    }
+                },
+            }
+        }
+    }
+
+    /// Counts the remaining entries without building them, by simply tracking
+    /// bracket depth on the token stream. An entry is any object sitting
+    /// directly in array nesting, or a bare top-level object — the same set
+    /// parse_all would return, so the count matches parse_all().len() while
+    /// being considerably faster as no values are interpreted.
+    /// @return The number of entries counted, or an error from the lexer
+    pub fn count_entries(&mut self) -> Result<usize, ParseError> {
+        // An entry sitting in the peek buffer was already taken off the stream
+        // but is still deliverable by parse_single, so it counts
+        let mut count: usize = match self.peeked {
+            Some(_) => 1,
+            None => 0,
+        };
+        // When counting resumes mid-stream the parser is already inside the
+        // document; start from its nesting so the closing brackets balance
+        let mut array_depth: usize = self.array_depth;
+        let mut object_depth: usize = match &self.state {
+            &State::Object | &State::Key(_) => 1,
+            _ => 0,
+        };
+        loop {
+            let token = match self.lexer.consume_token() {
+                Err(ParseError::EndOfData) => break,
+                Err(error) => return Err(error),
+                Ok(token) => token,
+            };
+            match token {
+                Token::ArrayStart => array_depth += 1,
+                Token::ObjectStart => object_depth += 1,
+                // A closing bracket with nothing open is a structural error,
+                // not a reason to panic on the depth arithmetic
+                Token::ObjectEnd if object_depth == 0 => {
+                    return Err(ParseError::UnexpectedToken{
+                        token_description: format!("{:?}", token),
+                        state_description: String::from("counting entries with no open bracket"),
+                    });
+                },
+                Token::ObjectEnd => {
+                    object_depth -= 1;
+                    // An object closing straight back into array nesting (or
+                    // ending a bare document) is exactly what parse_single
+                    // emits as an entry; objects nested deeper belong to the
+                    // entry that contains them
+                    if object_depth == 0 {
+                        count += 1;
+                    }
+                },
+                Token::ArrayEnd if array_depth == 0 => {
+                    return Err(ParseError::UnexpectedToken{
+                        token_description: format!("{:?}", token),
+                        state_description: String::from("counting entries with no open bracket"),
+                    });
+                },
+                Token::ArrayEnd => array_depth -= 1,
+                _ => {},
+            }
+        }
+        return Ok(count);
+    }
+
+    /// Parses every remaining entry of the data into a Vec
+    /// @return All entries if the data parsed cleanly, the first non-EndOfData error otherwise
+    pub fn parse_all(&mut self) -> Result<Vec<ResultEntry>, ParseError> {
+        let mut entries = Vec::new();
+        loop {
+            match self.parse_single() {
+                Err(ParseError::EndOfData) => break,
+                Err(error) => return Err(error),
+                Ok(entry) => entries.push(entry),
+            }
+        }
+        return Ok(entries);
+    }
+
+    /// Streams every remaining entry into the given callback, holding at most
+    /// one entry in memory at a time — the frugal sibling of parse_all for
+    /// aggregating over huge responses on the fly.
+    /// @return Ok(()) once the data is exhausted, the first non-EndOfData error otherwise
+    pub fn for_each_entry<F: FnMut(ResultEntry)>(&mut self, mut callback: F) -> Result<(), ParseError> {
+        loop {
+            match self.parse_single() {
+                Err(ParseError::EndOfData) => break,
+                Err(error) => return Err(error),
+                Ok(entry) => callback(entry),
+            }
+        }
+        return Ok(());
+    }
+
+    /// Inspects the next entry without consuming it: the following parse_single
+    /// returns the very same entry. The entry is parsed into a one-entry buffer
+    /// on first peek and handed out from there. A skip_entry discards the
+    /// buffered entry and count_entries includes it; parse_single_into and
+    /// parse_single_raw report PendingPeek instead of reordering the stream.
+    /// @return A reference to the next entry, or an error (including end of data)
+    pub fn peek_entry(&mut self) -> Result<&ResultEntry, ParseError> {
+        if self.peeked.is_none() {
+            let entry = self.parse_single()?;
+            self.peeked = Some(entry);
+        }
+
+        match &self.peeked {
+            &Some(ref entry) => return Ok(entry),
+            &None => unreachable!("peeked was filled right above"),
+        }
+    }
+
+    /// Parses up to n entries, leaving the parser positioned to resume afterwards.
+    /// Fewer than n entries are returned when the end of data is reached first.
+    /// @return The parsed entries, or the first non-EndOfData error encountered
+    pub fn parse_n(&mut self, n: usize) -> Result<Vec<ResultEntry>, ParseError> {
+        let mut entries = Vec::with_capacity(n);
+        for _ in 0..n {
+            match self.parse_single() {
+                Err(ParseError::EndOfData) => break,
+                Err(error) => return Err(error),
+                Ok(entry) => entries.push(entry),
+            }
+        }
+        return Ok(entries);
+    }
+}
+
+// Implementing Iterator lets callers write `for entry in parser { ... }` or collect
+// all entries, instead of hand-rolling the parse_single loop
+impl<'data> Iterator for Parser<'data> {
+    type Item = Result<ResultEntry, ParseError>;
+
+    /// @return None once the end of data is reached, otherwise the next entry or error
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.parse_single() {
+            Err(ParseError::EndOfData) => None,
+            result => Some(result),
+        }
+    }
+}
+
+// A chainable configuration surface for Parser. As options accumulate this
+// keeps Parser::new simple while letting callers spell out exactly the
+// behaviour they want in one expression.
+#[derive(Default)]
+pub struct ParserBuilder {
+    lenient: bool,
+    check_missing_fields: bool,
+    check_duplicate_keys: bool,
+    capture_unknown_keys: bool,
+    validate_symbol: bool,
+    symbol_filter: Option<Box<dyn Fn(&str) -> bool>>,
+    max_entries: Option<usize>,
+}
+
+impl ParserBuilder {
+    pub fn new() -> Self {
+        return ParserBuilder::default();
+    }
+
+    /// Ignore unrecognised keys instead of treating them as errors
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        return self;
+    }
+
+    /// Report objects that close without all required keys
+    pub fn check_missing_fields(mut self, check_missing_fields: bool) -> Self {
+        self.check_missing_fields = check_missing_fields;
+        return self;
+    }
+
+    /// Report objects repeating a key
+    pub fn check_duplicate_keys(mut self, check_duplicate_keys: bool) -> Self {
+        self.check_duplicate_keys = check_duplicate_keys;
+        return self;
+    }
+
+    /// Collect unrecognised keys into the extra map instead of erroring
+    pub fn capture_unknown_keys(mut self, capture_unknown_keys: bool) -> Self {
+        self.capture_unknown_keys = capture_unknown_keys;
+        return self;
+    }
+
+    /// Reject entries whose symbol field ends up empty
+    pub fn validate_symbol(mut self, validate_symbol: bool) -> Self {
+        self.validate_symbol = validate_symbol;
+        return self;
+    }
+
+    /// Only parse entries whose symbol the given predicate accepts
+    pub fn symbol_filter(mut self, predicate: impl Fn(&str) -> bool + 'static) -> Self {
+        self.symbol_filter = Some(Box::new(predicate));
+        return self;
+    }
+
+    /// Stop after the given number of entries with EntryLimitReached
+    pub fn max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = Some(max_entries);
+        return self;
+    }
+
+    /// Assembles a Parser over the given data with this configuration applied
+    /// @return The configured parser
+    pub fn build<'data>(self, data: &'data str) -> Parser<'data> {
+        let mut parser = Parser::new(data);
+        parser.set_lenient(self.lenient);
+        parser.set_check_missing_fields(self.check_missing_fields);
+        parser.set_check_duplicate_keys(self.check_duplicate_keys);
+        parser.set_capture_unknown_keys(self.capture_unknown_keys);
+        parser.set_validate_symbol(self.validate_symbol);
+        if let Some(predicate) = self.symbol_filter {
+            parser.symbol_filter = Some(predicate);
+        }
+        parser.max_entries = self.max_entries;
+        return parser;
     }
 }